@@ -1,13 +1,73 @@
-use chrono::{DateTime, Duration, Local, Timelike};
+use chrono::{DateTime, Duration, Local, NaiveDate, Timelike};
 use serde::{Deserialize, Serialize};
+use unicode_segmentation::UnicodeSegmentation;
+use crate::dateparse::parse_date;
+use crate::editor::LineEditor;
+use crate::quickadd::{parse_quick_add, parse_tag, push_tag, QuickAdd};
+use crate::events::{AppEvent, AppEventKind};
+use crate::history::{SessionOutcome, SessionRecord};
+use crate::stats::Stats;
 use crate::theme::{Theme, ThemeName};
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Priority {
+    #[default]
+    None,
+    Low,
+    Medium,
+    High,
+    Urgent,
+}
+
+impl Priority {
+    pub fn raised(self) -> Self {
+        match self {
+            Priority::None => Priority::Low,
+            Priority::Low => Priority::Medium,
+            Priority::Medium => Priority::High,
+            Priority::High | Priority::Urgent => Priority::Urgent,
+        }
+    }
+
+    pub fn lowered(self) -> Self {
+        match self {
+            Priority::None | Priority::Low => Priority::None,
+            Priority::Medium => Priority::Low,
+            Priority::High => Priority::Medium,
+            Priority::Urgent => Priority::High,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Task {
     pub id: usize,
     pub title: String,
     pub completed: bool,
     pub subtasks: Vec<Task>,
+    /// Seconds of work sessions spent focused on this task.
+    #[serde(default)]
+    pub focus_seconds: u64,
+    /// Work sessions completed while focused on this task.
+    #[serde(default)]
+    pub pomodoros: u32,
+    /// Number of pomodoros this task is expected to take.
+    #[serde(default)]
+    pub estimate: Option<u32>,
+    /// Hides the subtasks in the task list.
+    #[serde(default)]
+    pub collapsed: bool,
+    #[serde(default)]
+    pub priority: Priority,
+    /// Date the task has to be done by.
+    #[serde(default)]
+    pub due: Option<NaiveDate>,
+    /// Date the task is planned to be worked on.
+    #[serde(default)]
+    pub scheduled: Option<NaiveDate>,
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
 
 impl Task {
@@ -17,51 +77,344 @@ impl Task {
             title,
             completed: false,
             subtasks: Vec::new(),
+            focus_seconds: 0,
+            pomodoros: 0,
+            estimate: None,
+            collapsed: false,
+            priority: Priority::None,
+            due: None,
+            scheduled: None,
+            tags: Vec::new(),
+        }
+    }
+
+    /// True if the task is unfinished and its due date has passed.
+    pub fn is_overdue(&self, today: NaiveDate) -> bool {
+        !self.completed && self.due.is_some_and(|due| due < today)
+    }
+
+    pub fn is_due_today(&self, today: NaiveDate) -> bool {
+        !self.completed && self.due == Some(today)
+    }
+
+    /// Completed and total counts of all subtasks below this task.
+    pub fn subtask_progress(&self) -> (usize, usize) {
+        self.subtasks.iter().fold((0, 0), |(done, total), subtask| {
+            let (sub_done, sub_total) = subtask.subtask_progress();
+            (done + sub_done + subtask.completed as usize, total + sub_total + 1)
+        })
+    }
+
+    /// True if the task has any of `tags`, ignoring case.
+    pub fn has_any_tag(&self, tags: &[String]) -> bool {
+        self.tags
+            .iter()
+            .any(|tag| tags.iter().any(|wanted| tag.to_lowercase() == wanted.to_lowercase()))
+    }
+
+    /// True if the task is listed under a tag filter: it matches the filter
+    /// itself or has a subtask that does. An empty filter shows everything.
+    pub fn is_shown(&self, filter: &[String]) -> bool {
+        filter.is_empty() || self.has_any_tag(filter) || self.subtasks.iter().any(|t| t.is_shown(filter))
+    }
+
+    /// True if the title or one of the tags contains `query`, ignoring case.
+    /// A leading `#` in the query only has to match a tag.
+    pub fn matches_search(&self, query: &str) -> bool {
+        let query = query.to_lowercase();
+        let tag_query = query.trim_start_matches('#');
+        self.title.to_lowercase().contains(&query)
+            || self.tags.iter().any(|tag| tag.to_lowercase().contains(tag_query))
+    }
+
+    fn set_collapsed_recursive(&mut self, collapsed: bool) {
+        self.collapsed = collapsed && !self.subtasks.is_empty();
+        for subtask in &mut self.subtasks {
+            subtask.set_collapsed_recursive(collapsed);
         }
     }
+
+    /// Levels of subtasks below this task; zero if it has none.
+    fn subtree_depth(&self) -> usize {
+        self.subtasks.iter().map(|t| 1 + t.subtree_depth()).max().unwrap_or(0)
+    }
+
+    /// Focus time of this task and all of its subtasks.
+    pub fn total_focus_seconds(&self) -> u64 {
+        self.focus_seconds + self.subtasks.iter().map(Task::total_focus_seconds).sum::<u64>()
+    }
+
+    /// Pomodoros of this task and all of its subtasks.
+    pub fn total_pomodoros(&self) -> u32 {
+        self.pomodoros + self.subtasks.iter().map(Task::total_pomodoros).sum::<u32>()
+    }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+fn find_task_by_id(tasks: &[Task], id: usize) -> Option<&Task> {
+    tasks.iter().find_map(|task| {
+        if task.id == id {
+            Some(task)
+        } else {
+            find_task_by_id(&task.subtasks, id)
+        }
+    })
+}
+
+/// Tags typed as words, with or without a leading `#`, dropping repeats.
+fn parse_tags(input: &str) -> Result<Vec<String>, String> {
+    let mut tags = Vec::new();
+    for word in input.split_whitespace() {
+        push_tag(&mut tags, parse_tag(word)?);
+    }
+    Ok(tags)
+}
+
+/// Indices of `siblings` in the order they're listed: highest priority
+/// first when `by_priority` is set, keeping their own order among equal
+/// priorities. The tasks themselves are never reordered, so turning the
+/// sort off brings back the order they were given.
+pub fn view_order(siblings: &[Task], by_priority: bool) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..siblings.len()).collect();
+    if by_priority {
+        order.sort_by_key(|&idx| std::cmp::Reverse(siblings[idx].priority));
+    }
+    order
+}
+
+/// Indices of the `siblings` that appear in the task list, in list order,
+/// leaving out those the tag filter hides.
+pub fn listed_siblings(siblings: &[Task], by_priority: bool, filter: &[String]) -> Vec<usize> {
+    view_order(siblings, by_priority)
+        .into_iter()
+        .filter(|&idx| siblings[idx].is_shown(filter))
+        .collect()
+}
+
+fn find_task_mut_by_id(tasks: &mut [Task], id: usize) -> Option<&mut Task> {
+    for task in tasks {
+        if task.id == id {
+            return Some(task);
+        }
+        if let Some(found) = find_task_mut_by_id(&mut task.subtasks, id) {
+            return Some(found);
+        }
+    }
+    None
+}
+
+pub const DEFAULT_MAX_TITLE_LENGTH: usize = 200;
+
+/// Cuts `title` to at most `max` characters as the user sees them, so emoji
+/// and combining sequences are never split.
+pub fn truncate_title(title: &str, max: usize) -> &str {
+    match title.grapheme_indices(true).nth(max) {
+        Some((idx, _)) => title[..idx].trim_end(),
+        None => title,
+    }
+}
+
+/// Byte range in `text` of the first match of `query`, ignoring case. Some
+/// characters change length when lowercased, so the match is found in the
+/// lowercase text and mapped back to whole characters of the original.
+pub fn find_ignoring_case(text: &str, query: &str) -> Option<(usize, usize)> {
+    if query.is_empty() {
+        return None;
+    }
+    let mut lower = String::new();
+    // Start and end in `text` of the character each lowercase byte came from
+    let mut sources = Vec::new();
+    for (idx, c) in text.char_indices() {
+        for lower_c in c.to_lowercase() {
+            lower.push(lower_c);
+            sources.extend(std::iter::repeat_n((idx, idx + c.len_utf8()), lower_c.len_utf8()));
+        }
+    }
+    let query = query.to_lowercase();
+    let start = lower.find(&query)?;
+    let end = start + query.len();
+    Some((sources[start].0, sources[end - 1].1))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum PomodoroState {
     Work,
     ShortBreak,
     LongBreak,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TimerState {
     Stopped,
     Running,
     Paused,
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimerMode {
+    /// Every phase ends when its time is up.
+    #[default]
+    Classic,
+    /// Work keeps counting past zero until it is ended by hand.
+    Overtime,
+    /// Work is open-ended; the following break is a fraction of the time worked.
+    Flowtime,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PomodoroSettings {
+    pub work_minutes: u32,
+    pub short_break_minutes: u32,
+    pub long_break_minutes: u32,
+    pub long_break_interval: u32,
+    pub mode: TimerMode,
+    /// In flowtime mode, breaks last the time worked divided by this.
+    pub flowtime_break_ratio: u32,
+}
+
+impl PomodoroSettings {
+    pub fn duration_for(&self, state: PomodoroState) -> Duration {
+        let minutes = match state {
+            PomodoroState::Work => self.work_minutes,
+            PomodoroState::ShortBreak => self.short_break_minutes,
+            PomodoroState::LongBreak => self.long_break_minutes,
+        };
+        Duration::minutes(minutes as i64)
+    }
+
+    /// Replaces lengths and counts of zero, which a damaged or hand-edited
+    /// state file can hold, with the defaults. Returns the names of the
+    /// fields replaced.
+    fn fix_zeros(&mut self) -> Vec<&'static str> {
+        let defaults = Self::default();
+        let fields = [
+            ("work_minutes", &mut self.work_minutes, defaults.work_minutes),
+            ("short_break_minutes", &mut self.short_break_minutes, defaults.short_break_minutes),
+            ("long_break_minutes", &mut self.long_break_minutes, defaults.long_break_minutes),
+            ("long_break_interval", &mut self.long_break_interval, defaults.long_break_interval),
+            ("flowtime_break_ratio", &mut self.flowtime_break_ratio, defaults.flowtime_break_ratio),
+        ];
+        let mut fixed = Vec::new();
+        for (name, value, default) in fields {
+            if *value == 0 {
+                *value = default;
+                fixed.push(name);
+            }
+        }
+        fixed
+    }
+}
+
+impl Default for PomodoroSettings {
+    fn default() -> Self {
+        Self {
+            work_minutes: 25,
+            short_break_minutes: 5,
+            long_break_minutes: 15,
+            long_break_interval: 4,
+            mode: TimerMode::Classic,
+            flowtime_break_ratio: 5,
+        }
+    }
+}
+
+/// What happens when a phase ends.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TransitionPolicy {
+    /// Start breaks as soon as a work session ends.
+    pub auto_start_breaks: bool,
+    /// Start the next work session as soon as a break ends.
+    pub auto_start_work: bool,
+    /// Remind every this many minutes while a break waits to be started.
+    pub break_reminder_minutes: Option<u32>,
+}
+
+impl TransitionPolicy {
+    fn auto_starts(&self, state: PomodoroState) -> bool {
+        match state {
+            PomodoroState::Work => self.auto_start_work,
+            PomodoroState::ShortBreak | PomodoroState::LongBreak => self.auto_start_breaks,
+        }
+    }
+}
+
+/// A phase that has ended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompletedPhase {
+    pub state: PomodoroState,
+    pub started_at: DateTime<Local>,
+    pub ended_at: DateTime<Local>,
+    pub mode: TimerMode,
+    /// Planned length in seconds; `None` for open-ended flowtime work.
+    pub planned_seconds: Option<i64>,
+    /// Seconds the timer actually ran, leaving out pauses.
+    pub active_seconds: i64,
+}
+
+/// Most phases [`PomodoroTimer::resume_until`] records as having ended
+/// while the app was closed.
+const MAX_MISSED_PHASES: usize = 100;
+
+/// Most transitions [`PomodoroTimer::previous_phase`] can step back through.
+const MAX_PAST_PHASES: usize = 50;
+
 #[derive(Debug, Clone)]
 pub struct PomodoroTimer {
+    pub settings: PomodoroSettings,
     pub state: PomodoroState,
     pub timer_state: TimerState,
     pub duration: Duration,
     pub remaining: Duration,
     pub cycles: usize,
     pub start_time: Option<DateTime<Local>>,
+    /// Wall-clock time the current phase was first started; pauses don't move it.
+    pub phase_started_at: Option<DateTime<Local>>,
+    pub policy: TransitionPolicy,
+    /// When the last phase ended, while the next one hasn't been started.
+    idle_since: Option<DateTime<Local>>,
+    /// Set once an overtime phase has passed zero.
+    overtime_alerted: bool,
+    /// Phase and cycle count before each transition, most recent last, so
+    /// `previous_phase` can step back through them. Holds at most
+    /// `MAX_PAST_PHASES`.
+    past_phases: Vec<(PomodoroState, usize)>,
 }
 
 impl PomodoroTimer {
     pub fn new() -> Self {
+        let settings = PomodoroSettings::default();
+        let duration = settings.duration_for(PomodoroState::Work);
         Self {
+            settings,
             state: PomodoroState::Work,
             timer_state: TimerState::Stopped,
-            duration: Duration::minutes(25),
-            remaining: Duration::minutes(25),
+            duration,
+            remaining: duration,
             cycles: 0,
             start_time: None,
+            phase_started_at: None,
+            policy: TransitionPolicy::default(),
+            idle_since: None,
+            overtime_alerted: false,
+            past_phases: Vec::new(),
         }
     }
 
     pub fn start(&mut self) {
+        self.start_at(Local::now());
+    }
+
+    fn start_at(&mut self, time: DateTime<Local>) {
         if self.timer_state == TimerState::Stopped || self.timer_state == TimerState::Paused {
-            self.start_time = Some(Local::now());
+            self.start_time = Some(time);
+        }
+        if self.phase_started_at.is_none() {
+            self.phase_started_at = self.start_time;
         }
         self.timer_state = TimerState::Running;
+        self.idle_since = None;
     }
 
     pub fn pause(&mut self) {
@@ -82,53 +435,50 @@ impl PomodoroTimer {
         }
     }
 
+    /// Replaces the configured lengths. A stopped timer is reset to the new
+    /// length; a running or paused one keeps the time already elapsed.
+    pub fn set_settings(&mut self, settings: PomodoroSettings) {
+        if settings == self.settings {
+            return;
+        }
+        self.settings = settings;
+        if self.timer_state == TimerState::Stopped {
+            self.reset();
+        } else {
+            let elapsed = self.duration - self.remaining;
+            if !self.is_flowtime_break() {
+                self.duration = self.settings.duration_for(self.state);
+            }
+            self.remaining = (self.duration - elapsed).max(Duration::zero());
+        }
+    }
+
     pub fn reset(&mut self) {
-        // Ensure duration matches the current state (25 min = Work, 5 min = ShortBreak, 15 min = LongBreak)
-        self.sync_duration_with_state();
-        // Then ensure state matches duration (in case there was a mismatch)
-        self.sync_state_with_duration();
+        // Ensure duration matches the configured length for the current state,
+        // except for a flowtime break, whose length comes from the work before it
+        if !self.is_flowtime_break() {
+            self.sync_duration_with_state();
+        }
         self.timer_state = TimerState::Stopped;
         self.start_time = None;
+        self.phase_started_at = None;
+        self.idle_since = None;
+        self.overtime_alerted = false;
         self.remaining = self.duration;
     }
 
     fn sync_duration_with_state(&mut self) {
-        match self.state {
-            PomodoroState::Work => {
-                self.duration = Duration::minutes(25);
-            }
-            PomodoroState::ShortBreak => {
-                self.duration = Duration::minutes(5);
-            }
-            PomodoroState::LongBreak => {
-                self.duration = Duration::minutes(15);
-            }
-        }
+        self.duration = self.settings.duration_for(self.state);
     }
 
-    pub fn sync_state_with_duration(&mut self) {
-        let duration_minutes = self.duration.num_minutes();
-        match duration_minutes {
-            25 => {
-                if self.state != PomodoroState::Work {
-                    self.state = PomodoroState::Work;
-                }
-            }
-            5 => {
-                if self.state != PomodoroState::ShortBreak {
-                    self.state = PomodoroState::ShortBreak;
-                }
-            }
-            15 => {
-                if self.state != PomodoroState::LongBreak {
-                    self.state = PomodoroState::LongBreak;
-                }
-            }
-            _ => {
-                // If duration doesn't match any known state, sync duration to state
-                self.sync_duration_with_state();
-            }
-        }
+    fn is_flowtime_break(&self) -> bool {
+        self.settings.mode == TimerMode::Flowtime && self.state != PomodoroState::Work
+    }
+
+    /// Length of a flowtime break earned by `worked` time, at least a minute.
+    fn flowtime_break_for(&self, worked: Duration) -> Duration {
+        let ratio = self.settings.flowtime_break_ratio.max(1) as i32;
+        (worked / ratio).max(Duration::minutes(1))
     }
 
     pub fn update(&mut self) -> bool {
@@ -138,43 +488,264 @@ impl PomodoroTimer {
 
         if let Some(start) = self.start_time {
             let elapsed = Local::now() - start;
-            if elapsed >= self.remaining {
-                self.remaining = Duration::zero();
-                self.timer_state = TimerState::Stopped;
-                self.advance_cycle();
+            if elapsed >= self.remaining && !self.is_open_ended() {
+                let deadline = start + self.remaining;
+                self.finish_phase(deadline);
                 return true;
             }
         }
         false
     }
 
+    /// True while the current phase only ends when the user ends it.
+    pub fn is_open_ended(&self) -> bool {
+        match self.settings.mode {
+            TimerMode::Classic => false,
+            TimerMode::Overtime | TimerMode::Flowtime => self.state == PomodoroState::Work,
+        }
+    }
+
+    /// True while an overtime phase is past its planned length.
+    pub fn is_in_overtime(&self) -> bool {
+        self.settings.mode == TimerMode::Overtime && self.is_open_ended() && self.get_remaining_seconds() < 0
+    }
+
+    /// Returns true once when an overtime phase passes zero.
+    pub fn take_overtime_alert(&mut self) -> bool {
+        if self.overtime_alerted || self.timer_state != TimerState::Running {
+            return false;
+        }
+        self.overtime_alerted = self.is_in_overtime();
+        self.overtime_alerted
+    }
+
+    /// Ends a phase early or out of overtime, moving on as if its time had
+    /// run out. Returns the ended phase, or `None` if it never started.
+    pub fn end_phase(&mut self, now: DateTime<Local>) -> Option<CompletedPhase> {
+        let started_at = self.phase_started_at?;
+        let phase = CompletedPhase {
+            started_at,
+            ..self.ended_phase(now)
+        };
+        self.finish_phase(now);
+        Some(phase)
+    }
+
+    /// Describes the current phase as if it ended at `ended_at`.
+    pub fn ended_phase(&self, ended_at: DateTime<Local>) -> CompletedPhase {
+        let open_ended_work = self.settings.mode == TimerMode::Flowtime && self.state == PomodoroState::Work;
+        CompletedPhase {
+            state: self.state,
+            started_at: self.phase_started_at.unwrap_or(ended_at - self.duration),
+            ended_at,
+            mode: self.settings.mode,
+            planned_seconds: if open_ended_work { None } else { Some(self.duration.num_seconds()) },
+            active_seconds: self.elapsed_at(ended_at).num_seconds().max(0),
+        }
+    }
+
+    /// Active time spent in the current phase, excluding pauses.
+    fn elapsed_at(&self, now: DateTime<Local>) -> Duration {
+        let remaining = match (self.timer_state, self.start_time) {
+            (TimerState::Running, Some(start)) => self.remaining - (now - start),
+            _ => self.remaining,
+        };
+        self.duration - remaining
+    }
+
+    /// Ends the current phase at `ended_at` and moves to the next one,
+    /// starting it right away if the transition policy says so.
+    fn finish_phase(&mut self, ended_at: DateTime<Local>) {
+        let worked = self.elapsed_at(ended_at);
+        self.remaining = Duration::zero();
+        self.timer_state = TimerState::Stopped;
+        self.advance_cycle();
+        if self.is_flowtime_break() {
+            self.duration = self.flowtime_break_for(worked);
+            self.remaining = self.duration;
+        }
+        if self.policy.auto_starts(self.state) {
+            self.start_at(ended_at);
+        } else {
+            self.idle_since = Some(ended_at);
+        }
+    }
+
+    /// Returns true, at most once per reminder interval, while a break is
+    /// waiting to be started.
+    pub fn break_reminder_due(&mut self, now: DateTime<Local>) -> bool {
+        let (Some(minutes), Some(since)) = (self.policy.break_reminder_minutes, self.idle_since) else {
+            return false;
+        };
+        if self.state == PomodoroState::Work || self.timer_state != TimerState::Stopped || minutes == 0 {
+            return false;
+        }
+        if now - since >= Duration::minutes(minutes as i64) {
+            self.idle_since = Some(now);
+            return true;
+        }
+        false
+    }
+
+    /// Wall-clock time at which the running phase will end.
+    pub fn deadline(&self) -> Option<DateTime<Local>> {
+        match (self.timer_state, self.start_time) {
+            (TimerState::Running, Some(start)) => Some(start + self.remaining),
+            _ => None,
+        }
+    }
+
+    /// Resumes a phase that was running when the app closed and was due to end
+    /// at `deadline`. Returns the phases that finished in the meantime; more
+    /// than one if the next phases were started automatically. After
+    /// `MAX_MISSED_PHASES` the timer is left stopped at the next phase
+    /// instead of replaying the rest of a long absence.
+    pub fn resume_until(&mut self, deadline: DateTime<Local>, now: DateTime<Local>) -> Vec<CompletedPhase> {
+        let mut completed = Vec::new();
+        let mut deadline = deadline;
+        // The phase has been running since it was saved with `remaining` left
+        self.start_time = Some(deadline - self.remaining);
+        loop {
+            if deadline > now || self.is_open_ended() {
+                // Negative when an overtime phase ran past zero while closed
+                self.remaining = (deadline - now).min(self.duration);
+                self.start_time = Some(now);
+                self.timer_state = TimerState::Running;
+                return completed;
+            }
+            if completed.len() == MAX_MISSED_PHASES || self.duration <= Duration::zero() {
+                self.reset();
+                self.idle_since = Some(now);
+                return completed;
+            }
+
+            completed.push(self.ended_phase(deadline));
+            self.finish_phase(deadline);
+            if self.timer_state != TimerState::Running {
+                return completed;
+            }
+            deadline += self.duration;
+        }
+    }
+
     pub(crate) fn advance_cycle(&mut self) {
+        self.remember_phase();
         match self.state {
             PomodoroState::Work => {
                 self.cycles += 1;
-                if self.cycles.is_multiple_of(4) {
+                if self.cycles.is_multiple_of(self.settings.long_break_interval.max(1) as usize) {
                     self.state = PomodoroState::LongBreak;
-                    self.duration = Duration::minutes(15);
                 } else {
                     self.state = PomodoroState::ShortBreak;
-                    self.duration = Duration::minutes(5);
                 }
             }
             PomodoroState::ShortBreak | PomodoroState::LongBreak => {
                 self.state = PomodoroState::Work;
-                self.duration = Duration::minutes(25);
             }
         }
+        self.enter_phase();
+    }
+
+    fn remember_phase(&mut self) {
+        if self.past_phases.len() == MAX_PAST_PHASES {
+            self.past_phases.remove(0);
+        }
+        self.past_phases.push((self.state, self.cycles));
+    }
+
+    /// Sets up a fresh, not yet started phase for the current state.
+    fn enter_phase(&mut self) {
+        self.sync_duration_with_state();
         self.remaining = self.duration;
         self.start_time = None;
+        self.phase_started_at = None;
+        self.overtime_alerted = false;
+    }
+
+    /// Moves on to the next phase without crediting a cycle. Skipping work
+    /// always leads to a short break, since long breaks are only earned by
+    /// completed cycles. A running timer keeps running into the next phase.
+    /// In flowtime mode, skipping started work still earns a break in
+    /// proportion to the time worked.
+    pub fn skip_phase(&mut self) {
+        let was_running = self.timer_state == TimerState::Running;
+        let worked = self.phase_started_at.map(|_| self.elapsed_at(Local::now()));
+        self.remember_phase();
+        self.state = match self.state {
+            PomodoroState::Work => PomodoroState::ShortBreak,
+            PomodoroState::ShortBreak | PomodoroState::LongBreak => PomodoroState::Work,
+        };
+        self.enter_phase();
+        if let (true, Some(worked)) = (self.is_flowtime_break(), worked) {
+            self.duration = self.flowtime_break_for(worked);
+            self.remaining = self.duration;
+        }
+        self.timer_state = TimerState::Stopped;
+        self.idle_since = None;
+        if was_running {
+            self.start();
+        }
+    }
+
+    /// Goes back to the phase before the current one, restoring the cycle
+    /// count it had. Without a recorded transition, e.g. after a restart, the
+    /// previous phase is worked out from the long break schedule. Returns
+    /// false if there is nothing to go back to.
+    pub fn previous_phase(&mut self) -> bool {
+        let previous = self.past_phases.pop().or_else(|| match self.state {
+            PomodoroState::ShortBreak | PomodoroState::LongBreak => {
+                Some((PomodoroState::Work, self.cycles.saturating_sub(1)))
+            }
+            PomodoroState::Work if self.cycles == 0 => None,
+            PomodoroState::Work => {
+                let interval = self.settings.long_break_interval.max(1) as usize;
+                if self.cycles.is_multiple_of(interval) {
+                    Some((PomodoroState::LongBreak, self.cycles))
+                } else {
+                    Some((PomodoroState::ShortBreak, self.cycles))
+                }
+            }
+        });
+        let Some((state, cycles)) = previous else {
+            return false;
+        };
+        self.state = state;
+        self.cycles = cycles;
+        self.enter_phase();
+        self.timer_state = TimerState::Stopped;
+        self.idle_since = None;
+        true
+    }
+
+    /// Lengthens or shortens the current phase. The phase is kept at least a
+    /// minute long, and a timed phase can't be cut short into the past; it
+    /// ends on the next update instead.
+    pub fn adjust_duration(&mut self, delta: Duration) {
+        let duration = (self.duration + delta).max(Duration::minutes(1));
+        self.remaining += duration - self.duration;
+        self.duration = duration;
+        if !self.is_open_ended() {
+            let since_start = match (self.timer_state, self.start_time) {
+                (TimerState::Running, Some(start)) => Local::now() - start,
+                _ => Duration::zero(),
+            };
+            self.remaining = self.remaining.max(since_start);
+        }
+        self.overtime_alerted = self.overtime_alerted && self.is_in_overtime();
     }
 
+    /// Seconds left in the current phase; negative once an open-ended phase
+    /// runs past its planned length.
     pub fn get_remaining_seconds(&self) -> i64 {
         if self.timer_state == TimerState::Running {
             if let Some(start) = self.start_time {
                 let elapsed = Local::now() - start;
-                let remaining = self.remaining - elapsed;
-                remaining.num_seconds().max(0)
+                let remaining = (self.remaining - elapsed).num_seconds();
+                if self.is_open_ended() {
+                    remaining
+                } else {
+                    remaining.max(0)
+                }
             } else {
                 self.remaining.num_seconds()
             }
@@ -193,6 +764,18 @@ impl PomodoroTimer {
         let elapsed_seconds = total_seconds - remaining_seconds;
         (elapsed_seconds as f64 / total_seconds as f64).clamp(0.0, 1.0)
     }
+
+    /// Seconds of active time spent in the current phase.
+    pub fn get_elapsed_seconds(&self) -> i64 {
+        self.duration.num_seconds() - self.get_remaining_seconds()
+    }
+}
+
+/// Which of a task's dates is being edited.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateField {
+    Due,
+    Scheduled,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -200,7 +783,14 @@ pub enum InputMode {
     Normal,
     AddingTask,
     AddingSubtask(usize),
+    EditingEstimate,
+    EditingTask,
+    EditingDate(DateField),
+    EditingTags,
+    FilteringTags,
+    Searching,
     Menu,
+    Stats,
     ConfirmingDelete,
     ConfirmingClear,
 }
@@ -212,13 +802,41 @@ pub struct App {
     pub selected_path: Vec<usize>,
     pub pomodoro: PomodoroTimer,
     pub input_mode: InputMode,
-    pub input_buffer: String,
+    pub input_buffer: LineEditor,
     pub next_task_id: usize,
     pub theme: Theme,
     pub theme_name: ThemeName,
     pub last_c_key_time: Option<std::time::Instant>,
     pub menu_selection: usize,
-    pub save_notification_time: Option<std::time::Instant>,
+    pub notification: Option<Notification>,
+    pub history: Vec<SessionRecord>,
+    unsaved_sessions: usize,
+    /// Saved sessions changed since, as they were and as they are now.
+    amended_sessions: Vec<(SessionRecord, SessionRecord)>,
+    pub focused_task_id: Option<usize>,
+    pub daily_goal: u32,
+    /// Longest task title accepted, in characters; longer input is cut off.
+    pub max_title_length: usize,
+    /// Levels of subtasks allowed below a top-level task; `None` for no limit.
+    pub max_depth: Option<usize>,
+    /// Keeps siblings ordered by priority.
+    pub sort_by_priority: bool,
+    /// Only tasks with one of these tags, and their parents, are listed.
+    pub tag_filter: Vec<String>,
+    /// Text searched for with `/`; matching tasks are highlighted.
+    pub search_query: String,
+    /// First task row shown in the list, kept between frames.
+    pub task_list_offset: usize,
+    /// Task rows that fit in the list, as of the last frame.
+    pub task_list_height: usize,
+    events: Vec<AppEvent>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Notification {
+    pub message: String,
+    pub shown_at: std::time::Instant,
+    pub duration: std::time::Duration,
 }
 
 impl App {
@@ -229,13 +847,26 @@ impl App {
             selected_path: Vec::new(),
             pomodoro: PomodoroTimer::new(),
             input_mode: InputMode::Normal,
-            input_buffer: String::new(),
+            input_buffer: LineEditor::default(),
             next_task_id: 1,
             theme: Theme::default(),
             theme_name: ThemeName::Default,
             last_c_key_time: None,
             menu_selection: 0,
-            save_notification_time: None,
+            notification: None,
+            history: Vec::new(),
+            unsaved_sessions: 0,
+            amended_sessions: Vec::new(),
+            focused_task_id: None,
+            daily_goal: 8,
+            max_title_length: DEFAULT_MAX_TITLE_LENGTH,
+            max_depth: None,
+            sort_by_priority: false,
+            tag_filter: Vec::new(),
+            search_query: String::new(),
+            task_list_offset: 0,
+            task_list_height: 0,
+            events: Vec::new(),
         }
     }
 
@@ -246,6 +877,7 @@ impl App {
             "Save Tasks",
             "Clear All Tasks",
             "Change Theme",
+            "Statistics",
             "Quit",
         ]
     }
@@ -290,6 +922,186 @@ impl App {
         self.set_theme(next_theme);
     }
 
+    /// Advances the Pomodoro timer, recording the phase in the session
+    /// history when it finishes. Returns the finished phase on a transition.
+    pub fn update_pomodoro(&mut self) -> Option<CompletedPhase> {
+        let phase = self.pomodoro.deadline().map(|deadline| self.pomodoro.ended_phase(deadline));
+        if !self.pomodoro.update() {
+            return None;
+        }
+        let phase = phase.unwrap_or_else(|| self.pomodoro.ended_phase(Local::now()));
+        self.record_session(&phase, SessionOutcome::Completed);
+        self.emit_auto_start();
+        Some(phase)
+    }
+
+    /// Ends an open-ended phase by hand, crediting it as completed.
+    pub fn end_pomodoro_phase(&mut self) -> Option<CompletedPhase> {
+        if !self.pomodoro.is_open_ended() {
+            return None;
+        }
+        let phase = self.pomodoro.end_phase(Local::now())?;
+        self.record_session(&phase, SessionOutcome::Completed);
+        self.emit_auto_start();
+        Some(phase)
+    }
+
+    fn emit_auto_start(&mut self) {
+        if self.pomodoro.timer_state == TimerState::Running {
+            let next = self.pomodoro.state;
+            self.emit(AppEventKind::phase_start(next), Some(next), None, None);
+        }
+    }
+
+    /// Starts or pauses the Pomodoro timer. Starting a phase that hasn't run
+    /// yet queues a phase start event.
+    pub fn toggle_pomodoro(&mut self) {
+        let fresh = self.pomodoro.phase_started_at.is_none();
+        self.pomodoro.toggle();
+        if fresh && self.pomodoro.timer_state == TimerState::Running {
+            let phase = self.pomodoro.state;
+            self.emit(AppEventKind::phase_start(phase), Some(phase), None, None);
+        }
+    }
+
+    /// Resets the Pomodoro timer, recording the current phase as abandoned if
+    /// it had been started.
+    pub fn reset_pomodoro(&mut self) {
+        if self.pomodoro.phase_started_at.is_some() {
+            let phase = self.pomodoro.ended_phase(Local::now());
+            self.record_session(&phase, SessionOutcome::Reset);
+        }
+        self.pomodoro.reset();
+    }
+
+    /// Skips to the next phase without crediting a cycle, recording the
+    /// current phase as skipped if it had been started.
+    pub fn skip_pomodoro_phase(&mut self) {
+        if self.pomodoro.phase_started_at.is_some() {
+            let phase = self.pomodoro.ended_phase(Local::now());
+            self.record_session(&phase, SessionOutcome::Skipped);
+        }
+        self.pomodoro.skip_phase();
+        self.emit_auto_start();
+    }
+
+    /// Goes back to the previous phase, recording the current one as
+    /// abandoned if it had been started. Going back to a completed work
+    /// session takes back its credit, since it will be worked again.
+    pub fn previous_pomodoro_phase(&mut self) -> bool {
+        let phase = self
+            .pomodoro
+            .phase_started_at
+            .map(|_| self.pomodoro.ended_phase(Local::now()));
+        let cycles = self.pomodoro.cycles;
+        if !self.pomodoro.previous_phase() {
+            return false;
+        }
+        if let Some(phase) = phase {
+            self.record_session(&phase, SessionOutcome::Reset);
+        }
+        if self.pomodoro.state == PomodoroState::Work && self.pomodoro.cycles < cycles {
+            self.uncredit_last_work_session();
+        }
+        true
+    }
+
+    /// Marks the latest completed work session as reset, and takes its
+    /// pomodoro back from the task if that task is still focused.
+    fn uncredit_last_work_session(&mut self) {
+        // Sessions abandoned since, such as the one just left, earned nothing
+        let Some(idx) = self
+            .history
+            .iter()
+            .rposition(|r| r.phase == PomodoroState::Work && r.outcome == SessionOutcome::Completed)
+        else {
+            return;
+        };
+        let original = self.history[idx].clone();
+        self.history[idx].outcome = SessionOutcome::Reset;
+        if idx < self.history.len() - self.unsaved_sessions {
+            self.amended_sessions.push((original.clone(), self.history[idx].clone()));
+        }
+        if original.task_id.is_some() && original.task_id == self.focused_task_id {
+            if let Some(task) = self.focused_task_id.and_then(|id| find_task_mut_by_id(&mut self.tasks, id)) {
+                task.pomodoros = task.pomodoros.saturating_sub(1);
+            }
+        }
+    }
+
+    /// Adds `minutes` to the current phase, or takes them off if negative.
+    pub fn adjust_pomodoro(&mut self, minutes: i64) {
+        self.pomodoro.adjust_duration(Duration::minutes(minutes));
+    }
+
+    fn record_session(&mut self, phase: &CompletedPhase, outcome: SessionOutcome) {
+        if phase.state == PomodoroState::Work {
+            if let Some(task) = self.focused_task_id.and_then(|id| find_task_mut_by_id(&mut self.tasks, id)) {
+                task.focus_seconds += phase.active_seconds as u64;
+                if outcome == SessionOutcome::Completed {
+                    task.pomodoros += 1;
+                }
+            }
+        }
+
+        let task = self.focused_task().or_else(|| self.get_task_at_path(&self.selected_path));
+        self.history.push(SessionRecord {
+            phase: phase.state,
+            started_at: phase.started_at,
+            ended_at: phase.ended_at,
+            outcome,
+            task_id: task.map(|t| t.id),
+            task_title: task.map(|t| t.title.clone()),
+            mode: phase.mode,
+            planned_seconds: phase.planned_seconds,
+            active_seconds: Some(phase.active_seconds),
+        });
+        self.unsaved_sessions += 1;
+        self.emit(AppEventKind::phase_end(phase.state), Some(phase.state), Some(outcome), None);
+    }
+
+    /// Queues an event. Phase events carry the focused task unless `task_id`
+    /// names another one.
+    fn emit(&mut self, event: AppEventKind, phase: Option<PomodoroState>, outcome: Option<SessionOutcome>, task_id: Option<usize>) {
+        let task = match task_id {
+            Some(id) => find_task_by_id(&self.tasks, id),
+            None => self.focused_task(),
+        };
+        self.events.push(AppEvent {
+            event,
+            timestamp: Local::now(),
+            phase,
+            outcome,
+            cycles: self.pomodoro.cycles,
+            task_id: task.map(|t| t.id),
+            task_title: task.map(|t| t.title.clone()),
+        });
+    }
+
+    /// Returns and clears the events queued since the last call.
+    pub fn take_events(&mut self) -> Vec<AppEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    pub fn focused_task(&self) -> Option<&Task> {
+        self.focused_task_id.and_then(|id| find_task_by_id(&self.tasks, id))
+    }
+
+    /// Focuses the selected task so work sessions are credited to it, or
+    /// clears the focus if it is already focused.
+    pub fn toggle_focus(&mut self) {
+        let selected_id = self.get_task_at_path(&self.selected_path).map(|t| t.id);
+        self.focused_task_id = if self.focused_task_id == selected_id {
+            None
+        } else {
+            selected_id
+        };
+    }
+
+    pub fn get_stats(&self) -> Stats {
+        Stats::from_history(&self.history, Local::now().date_naive(), 30)
+    }
+
     pub fn get_current_time(&self) -> String {
         let now = Local::now();
         let hour24 = now.hour();
@@ -306,21 +1118,102 @@ impl App {
         format!("{}  {}", date_str, time_str)
     }
 
-    pub fn add_task(&mut self, title: String) -> bool {
-        let trimmed = title.trim();
-        if trimmed.is_empty() {
-            return false;
-        }
-        let limited_title = if trimmed.len() > 200 {
-            &trimmed[..200]
-        } else {
-            trimmed
-        };
-        let task = Task::new(self.next_task_id, limited_title.to_string());
-        self.next_task_id += 1;
+    /// Adds a top-level task. Quick-add tokens in `title` (see
+    /// [`parse_quick_add`]) fill in its tags, priority, due date and estimate.
+    pub fn add_task(&mut self, title: String) -> Result<(), String> {
+        let task = self.new_task_from_input(&title)?;
+        let id = task.id;
         self.tasks.push(task);
         self.validate_selected_index();
-        true
+        self.emit(AppEventKind::TaskAdded, None, None, Some(id));
+        Ok(())
+    }
+
+    /// Builds a task from typed input, using up an id only if it parses.
+    fn new_task_from_input(&mut self, input: &str) -> Result<Task, String> {
+        let QuickAdd { title, tags, priority, due, estimate } = parse_quick_add(input, Local::now().date_naive())?;
+        if title.is_empty() {
+            return Err("Task title is empty".to_string());
+        }
+        let mut task = Task::new(self.next_task_id, truncate_title(&title, self.max_title_length).to_string());
+        self.next_task_id += 1;
+        task.tags = tags;
+        task.priority = priority.unwrap_or_default();
+        task.due = due;
+        task.estimate = estimate;
+        Ok(task)
+    }
+
+    /// Replaces the title of the selected task. Quick-add tokens in `title`
+    /// set the estimate, priority and due date or add tags, the same as when
+    /// adding; details without a token are left as they were.
+    pub fn rename_selected_task(&mut self, title: String) -> Result<(), String> {
+        let QuickAdd { title, tags, priority, due, estimate } = parse_quick_add(&title, Local::now().date_naive())?;
+        if title.is_empty() {
+            return Err("Task title is empty".to_string());
+        }
+        let limited_title = truncate_title(&title, self.max_title_length).to_string();
+        let path = self.selected_path.clone();
+        let task = self.get_task_mut_at_path(&path).ok_or("No task selected")?;
+        task.title = limited_title;
+        for tag in &tags {
+            push_tag(&mut task.tags, tag);
+        }
+        task.priority = priority.unwrap_or(task.priority);
+        task.due = due.or(task.due);
+        task.estimate = estimate.or(task.estimate);
+        Ok(())
+    }
+
+    /// Titles of the tasks above the selected one, outermost first.
+    pub fn selected_ancestor_titles(&self) -> Vec<&str> {
+        let Some(mut task) = self.tasks.get(self.selected_index) else {
+            return Vec::new();
+        };
+        let mut titles = Vec::new();
+        for &idx in &self.selected_path {
+            titles.push(task.title.as_str());
+            match task.subtasks.get(idx) {
+                Some(subtask) => task = subtask,
+                None => break,
+            }
+        }
+        titles
+    }
+
+    pub fn get_selected_title(&self) -> Option<&str> {
+        self.get_task_at_path(&self.selected_path).map(|t| t.title.as_str())
+    }
+
+    /// True while a task title is being typed that is longer than
+    /// `max_title_length` and will be cut off when confirmed. Quick-add
+    /// tokens don't count, since they're taken out of the title first.
+    pub fn is_input_truncated(&self) -> bool {
+        let editing_title = matches!(
+            self.input_mode,
+            InputMode::AddingTask | InputMode::AddingSubtask(_) | InputMode::EditingTask
+        );
+        if !editing_title {
+            return false;
+        }
+        let input = self.input_buffer.text();
+        let title = match parse_quick_add(input, Local::now().date_naive()) {
+            Ok(parsed) => parsed.title,
+            Err(_) => input.trim().to_string(),
+        };
+        title.graphemes(true).count() > self.max_title_length
+    }
+
+    /// Switches to an input mode with `text` in the buffer and the cursor at its end.
+    pub fn start_input(&mut self, mode: InputMode, text: String) {
+        self.input_mode = mode;
+        self.input_buffer = LineEditor::new(text);
+    }
+
+    /// Leaves input mode and clears the buffer.
+    pub fn cancel_input(&mut self) {
+        self.input_mode = InputMode::Normal;
+        self.input_buffer.clear();
     }
 
     fn get_task_mut_at_path(&mut self, path: &[usize]) -> Option<&mut Task> {
@@ -371,32 +1264,101 @@ impl App {
         }
     }
 
-    pub fn add_subtask(&mut self, _parent_id: usize, title: String) -> bool {
-        let trimmed = title.trim();
-        if trimmed.is_empty() {
-            return false;
-        }
+    /// Adds a subtask under the selected task, parsing quick-add tokens like
+    /// [`App::add_task`].
+    pub fn add_subtask(&mut self, _parent_id: usize, title: String) -> Result<(), String> {
         let path_len = self.selected_path.len();
-        if path_len >= 4 {
-            return false; // Maximum depth reached
+        if let Some(max) = self.max_depth.filter(|&max| path_len >= max) {
+            return Err(format!("Subtasks can't be nested more than {} levels deep", max));
         }
-        let limited_title = if trimmed.len() > 200 {
-            &trimmed[..200]
+        let path = self.selected_path.clone();
+        if self.get_task_at_path(&path).is_none() {
+            return Err("No task selected".to_string());
+        }
+        let subtask = self.new_task_from_input(&title)?;
+        let new_id = subtask.id;
+        let task = self.get_task_mut_at_path(&path).expect("selected task");
+        task.subtasks.push(subtask);
+        task.collapsed = false;
+        self.emit(AppEventKind::TaskAdded, None, None, Some(new_id));
+        Ok(())
+    }
+
+    pub fn get_selected_estimate(&self) -> Option<u32> {
+        self.get_task_at_path(&self.selected_path).and_then(|t| t.estimate)
+    }
+
+    /// Sets the pomodoro estimate of the selected task from user input. An
+    /// empty input or zero clears the estimate.
+    pub fn set_selected_estimate(&mut self, input: &str) -> Result<(), String> {
+        let trimmed = input.trim();
+        let estimate = if trimmed.is_empty() {
+            None
         } else {
-            trimmed
+            match trimmed.parse::<u32>() {
+                Ok(0) => None,
+                Ok(n) => Some(n),
+                Err(_) => return Err(format!("Invalid estimate '{}', use a number of pomodoros", trimmed)),
+            }
         };
         let path = self.selected_path.clone();
-        let new_id = self.next_task_id;
-        self.next_task_id += 1;
-        if let Some(task) = self.get_task_mut_at_path(&path) {
-            let subtask = Task::new(new_id, limited_title.to_string());
-            task.subtasks.push(subtask);
-            true
+        let task = self.get_task_mut_at_path(&path).ok_or("No task selected")?;
+        task.estimate = estimate;
+        Ok(())
+    }
+
+    pub fn get_selected_date(&self, field: DateField) -> Option<NaiveDate> {
+        let task = self.get_task_at_path(&self.selected_path)?;
+        match field {
+            DateField::Due => task.due,
+            DateField::Scheduled => task.scheduled,
+        }
+    }
+
+    /// Sets the due or scheduled date of the selected task from a date
+    /// expression such as `fri` or `2026-11-02` (see [`parse_date`]). An
+    /// empty input clears the date.
+    pub fn set_selected_date(&mut self, field: DateField, input: &str) -> bool {
+        let trimmed = input.trim();
+        let date = if trimmed.is_empty() {
+            None
         } else {
-            false
+            match parse_date(trimmed, Local::now().date_naive()) {
+                Ok(date) => Some(date),
+                Err(_) => return false,
+            }
+        };
+        let path = self.selected_path.clone();
+        match self.get_task_mut_at_path(&path) {
+            Some(task) => {
+                match field {
+                    DateField::Due => task.due = date,
+                    DateField::Scheduled => task.scheduled = date,
+                }
+                true
+            }
+            None => false,
         }
     }
 
+    /// Unfinished tasks due on `today` and unfinished tasks past their due
+    /// date, counting subtasks.
+    pub fn due_counts(&self, today: NaiveDate) -> (usize, usize) {
+        fn count(tasks: &[Task], today: NaiveDate, counts: &mut (usize, usize)) {
+            for task in tasks {
+                if task.is_due_today(today) {
+                    counts.0 += 1;
+                } else if task.is_overdue(today) {
+                    counts.1 += 1;
+                }
+                count(&task.subtasks, today, counts);
+            }
+        }
+        let mut counts = (0, 0);
+        count(&self.tasks, today, &mut counts);
+        counts
+    }
+
     fn toggle_completion_recursive(task: &mut Task, new_state: bool) {
         task.completed = new_state;
         for subtask in &mut task.subtasks {
@@ -409,46 +1371,61 @@ impl App {
         if let Some(task) = self.get_task_mut_at_path(&path) {
             let new_state = !task.completed;
             Self::toggle_completion_recursive(task, new_state);
+            if new_state {
+                let id = task.id;
+                self.emit(AppEventKind::TaskCompleted, None, None, Some(id));
+            }
         }
     }
 
-    fn count_all_items(task: &Task) -> usize {
-        1 + task.subtasks.iter().map(Self::count_all_items).sum::<usize>()
+    /// Rows `task` takes up in the list: itself and its visible subtasks.
+    /// Tasks hidden by the tag filter take up none.
+    fn count_all_items(task: &Task, filter: &[String]) -> usize {
+        if !task.is_shown(filter) {
+            return 0;
+        }
+        if task.collapsed {
+            return 1;
+        }
+        1 + task.subtasks.iter().map(|t| Self::count_all_items(t, filter)).sum::<usize>()
     }
 
     fn get_flat_index(&self, task_idx: usize, path: &[usize]) -> usize {
         let mut flat_idx = 0;
-        for i in 0..task_idx {
-            flat_idx += Self::count_all_items(&self.tasks[i]);
-        }
-        if let Some(task) = self.tasks.get(task_idx) {
-            let mut current = task;
-            for &p in path {
-                for i in 0..p {
-                    flat_idx += Self::count_all_items(&current.subtasks[i]);
-                }
-                flat_idx += 1;
-                current = &current.subtasks[p];
+        let mut siblings = self.tasks.as_slice();
+        for (depth, &idx) in std::iter::once(&task_idx).chain(path).enumerate() {
+            if depth > 0 {
+                flat_idx += 1; // The parent's own row
+            }
+            for before in view_order(siblings, self.sort_by_priority).into_iter().take_while(|&i| i != idx) {
+                flat_idx += Self::count_all_items(&siblings[before], &self.tag_filter);
+            }
+            match siblings.get(idx) {
+                Some(task) => siblings = &task.subtasks,
+                None => break,
             }
         }
         flat_idx
     }
 
     fn find_item_at_flat_index(&self, target_flat: &mut usize) -> Option<(usize, Vec<usize>)> {
-        for (task_idx, task) in self.tasks.iter().enumerate() {
+        for task_idx in self.listed(&self.tasks) {
             if *target_flat == 0 {
                 return Some((task_idx, Vec::new()));
             }
             *target_flat -= 1;
-            if let Some((path, _remaining)) = Self::find_in_subtasks(task, &mut *target_flat, Vec::new()) {
+            if let Some((path, _remaining)) = self.find_in_subtasks(&self.tasks[task_idx], &mut *target_flat, Vec::new()) {
                 return Some((task_idx, path));
             }
         }
         None
     }
 
-    fn find_in_subtasks(task: &Task, target_flat: &mut usize, mut path: Vec<usize>) -> Option<(Vec<usize>, usize)> {
-        for (idx, subtask) in task.subtasks.iter().enumerate() {
+    fn find_in_subtasks(&self, task: &Task, target_flat: &mut usize, mut path: Vec<usize>) -> Option<(Vec<usize>, usize)> {
+        if task.collapsed {
+            return None;
+        }
+        for idx in self.listed(&task.subtasks) {
             if *target_flat == 0 {
                 path.push(idx);
                 return Some((path, *target_flat));
@@ -456,13 +1433,53 @@ impl App {
             *target_flat -= 1;
             let mut new_path = path.clone();
             new_path.push(idx);
-            if let Some((found_path, _remaining)) = Self::find_in_subtasks(subtask, target_flat, new_path) {
+            if let Some((found_path, _remaining)) = self.find_in_subtasks(&task.subtasks[idx], target_flat, new_path) {
                 return Some((found_path, *target_flat));
             }
         }
         None
     }
 
+    fn listed(&self, siblings: &[Task]) -> Vec<usize> {
+        listed_siblings(siblings, self.sort_by_priority, &self.tag_filter)
+    }
+
+    /// Number of rows in the task list, leaving out folded subtasks.
+    pub fn visible_row_count(&self) -> usize {
+        self.tasks.iter().map(|t| Self::count_all_items(t, &self.tag_filter)).sum()
+    }
+
+    /// Row of the selected item in the task list.
+    pub fn selected_row(&self) -> usize {
+        self.get_flat_index(self.selected_index, &self.selected_path)
+    }
+
+    fn select_row(&mut self, row: usize) {
+        let mut target = row.min(self.visible_row_count().saturating_sub(1));
+        if let Some((task_idx, path)) = self.find_item_at_flat_index(&mut target) {
+            self.selected_index = task_idx;
+            self.selected_path = path;
+        }
+    }
+
+    pub fn move_selection_page_up(&mut self) {
+        let page = self.task_list_height.max(1);
+        self.select_row(self.selected_row().saturating_sub(page));
+    }
+
+    pub fn move_selection_page_down(&mut self) {
+        let page = self.task_list_height.max(1);
+        self.select_row(self.selected_row() + page);
+    }
+
+    pub fn select_first_row(&mut self) {
+        self.select_row(0);
+    }
+
+    pub fn select_last_row(&mut self) {
+        self.select_row(self.visible_row_count().saturating_sub(1));
+    }
+
     pub fn move_selection_up(&mut self) {
         let current_flat = self.get_flat_index(self.selected_index, &self.selected_path);
         if current_flat > 0 {
@@ -476,8 +1493,8 @@ impl App {
 
     pub fn move_selection_down(&mut self) {
         let current_flat = self.get_flat_index(self.selected_index, &self.selected_path);
-        let total_items: usize = self.tasks.iter().map(Self::count_all_items).sum();
-        if current_flat < total_items - 1 {
+        let total_items = self.visible_row_count();
+        if current_flat + 1 < total_items {
             let mut new_flat = current_flat + 1;
             if let Some((new_task_idx, new_path)) = self.find_item_at_flat_index(&mut new_flat) {
                 self.selected_index = new_task_idx;
@@ -486,11 +1503,295 @@ impl App {
         }
     }
 
+    /// The list holding the selected item, and the item's index in it.
+    fn selected_siblings_mut(&mut self) -> Option<(&mut Vec<Task>, usize)> {
+        match self.selected_path.split_last() {
+            None => {
+                let idx = self.selected_index;
+                (idx < self.tasks.len()).then_some((&mut self.tasks, idx))
+            }
+            Some((&idx, parent_path)) => {
+                let parent_path = parent_path.to_vec();
+                let parent = self.get_task_mut_at_path(&parent_path)?;
+                (idx < parent.subtasks.len()).then_some((&mut parent.subtasks, idx))
+            }
+        }
+    }
+
+    /// Points the selection at index `idx` among the selected item's siblings.
+    fn select_sibling(&mut self, idx: usize) {
+        match self.selected_path.last_mut() {
+            Some(last) => *last = idx,
+            None => self.selected_index = idx,
+        }
+    }
+
+    /// Moves the selected item above the listed sibling before it, passing
+    /// over siblings the tag filter hides. While sorting by priority, items
+    /// only move among siblings of the same priority.
+    pub fn move_task_up(&mut self) -> bool {
+        self.move_task(true)
+    }
+
+    /// Moves the selected item below the listed sibling after it.
+    pub fn move_task_down(&mut self) -> bool {
+        self.move_task(false)
+    }
+
+    fn move_task(&mut self, up: bool) -> bool {
+        let sorted = self.sort_by_priority;
+        let filter = self.tag_filter.clone();
+        let Some((siblings, idx)) = self.selected_siblings_mut() else {
+            return false;
+        };
+        let listed = listed_siblings(siblings, sorted, &filter);
+        let Some(pos) = listed.iter().position(|&i| i == idx) else {
+            return false;
+        };
+        let target = if up { pos.checked_sub(1).map(|p| listed[p]) } else { listed.get(pos + 1).copied() };
+        let Some(target) = target else {
+            return false;
+        };
+        if sorted && siblings[target].priority != siblings[idx].priority {
+            return false;
+        }
+        let task = siblings.remove(idx);
+        siblings.insert(target, task);
+        self.select_sibling(target);
+        true
+    }
+
+    /// Makes the selected item the last subtask of the sibling above it, as
+    /// long as it and its own subtasks stay within the depth limit.
+    pub fn indent_task(&mut self) -> bool {
+        let depth = self.selected_path.len();
+        let max_depth = self.max_depth;
+        let sorted = self.sort_by_priority;
+        let filter = self.tag_filter.clone();
+        let Some((siblings, idx)) = self.selected_siblings_mut() else {
+            return false;
+        };
+        let new_depth = depth + 1 + siblings[idx].subtree_depth();
+        if max_depth.is_some_and(|max| new_depth > max) {
+            return false;
+        }
+        // The new parent is the sibling listed above it
+        let listed = listed_siblings(siblings, sorted, &filter);
+        let Some(pos) = listed.iter().position(|&i| i == idx) else {
+            return false;
+        };
+        let Some(parent) = pos.checked_sub(1).map(|p| listed[p]) else {
+            return false;
+        };
+        let task = siblings.remove(idx);
+        let parent_idx = if parent > idx { parent - 1 } else { parent };
+        let new_parent = &mut siblings[parent_idx];
+        new_parent.subtasks.push(task);
+        new_parent.collapsed = false;
+        let new_idx = new_parent.subtasks.len() - 1;
+        self.select_sibling(parent_idx);
+        self.selected_path.push(new_idx);
+        true
+    }
+
+    /// Moves the selected subtask up a level, placing it right after its
+    /// former parent.
+    pub fn outdent_task(&mut self) -> bool {
+        if self.selected_path.is_empty() {
+            return false;
+        }
+        let Some((siblings, idx)) = self.selected_siblings_mut() else {
+            return false;
+        };
+        let task = siblings.remove(idx);
+        self.selected_path.pop();
+        // The parent was resolved on the way to its subtask, so it exists
+        let (siblings, parent_idx) = self.selected_siblings_mut().expect("parent of selected task");
+        siblings.insert(parent_idx + 1, task);
+        self.select_sibling(parent_idx + 1);
+        true
+    }
+
+    pub fn raise_selected_priority(&mut self) -> bool {
+        self.change_selected_priority(Priority::raised)
+    }
+
+    pub fn lower_selected_priority(&mut self) -> bool {
+        self.change_selected_priority(Priority::lowered)
+    }
+
+    fn change_selected_priority(&mut self, change: fn(Priority) -> Priority) -> bool {
+        let path = self.selected_path.clone();
+        let Some(task) = self.get_task_mut_at_path(&path) else {
+            return false;
+        };
+        let priority = change(task.priority);
+        if priority == task.priority {
+            return false;
+        }
+        task.priority = priority;
+        true
+    }
+
+    /// Turns listing siblings by priority on or off. Only the list order
+    /// changes; the tasks keep the order they were given.
+    pub fn toggle_priority_sort(&mut self) {
+        self.sort_by_priority = !self.sort_by_priority;
+    }
+
+    /// Folds or unfolds the subtasks of the selected item.
+    pub fn toggle_collapsed(&mut self) -> bool {
+        let path = self.selected_path.clone();
+        match self.get_task_mut_at_path(&path) {
+            Some(task) if !task.subtasks.is_empty() => {
+                task.collapsed = !task.collapsed;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Folds or unfolds every task with subtasks. Folding everything moves
+    /// the selection up to its top-level task.
+    pub fn set_all_collapsed(&mut self, collapsed: bool) {
+        for task in &mut self.tasks {
+            task.set_collapsed_recursive(collapsed);
+        }
+        if collapsed {
+            self.selected_path.clear();
+        }
+    }
+
+    pub fn get_selected_tags(&self) -> Option<&[String]> {
+        self.get_task_at_path(&self.selected_path).map(|t| t.tags.as_slice())
+    }
+
+    /// Replaces the tags of the selected task with the words of `input`; a
+    /// leading `#` on each is optional. An empty input removes all tags.
+    pub fn set_selected_tags(&mut self, input: &str) -> Result<(), String> {
+        let tags = parse_tags(input)?;
+        let path = self.selected_path.clone();
+        let task = self.get_task_mut_at_path(&path).ok_or("No task selected")?;
+        task.tags = tags;
+        self.select_shown_row();
+        Ok(())
+    }
+
+    /// Lists only tasks tagged with one of the words of `input`, along with
+    /// their parents. An empty input shows every task again.
+    pub fn set_tag_filter(&mut self, input: &str) -> Result<(), String> {
+        self.tag_filter = parse_tags(input)?;
+        self.select_shown_row();
+        Ok(())
+    }
+
+    /// Moves the selection off a task the tag filter hides, onto the row
+    /// that took its place.
+    fn select_shown_row(&mut self) {
+        if !self.is_selected_shown() {
+            self.select_row(self.selected_row());
+        }
+    }
+
+    fn is_selected_shown(&self) -> bool {
+        self.get_task_at_path(&self.selected_path)
+            .is_some_and(|t| t.is_shown(&self.tag_filter))
+    }
+
+    /// Top-level index and subtask path of every task matching the search,
+    /// in list order. Tasks inside folded subtrees count; tasks hidden by the
+    /// tag filter don't.
+    pub fn search_matches(&self) -> Vec<(usize, Vec<usize>)> {
+        self.search_candidates()
+            .into_iter()
+            .filter_map(|(item, matched)| matched.then_some(item))
+            .collect()
+    }
+
+    /// Every listed task in list order, folded ones included, paired with
+    /// whether it matches the search.
+    fn search_candidates(&self) -> Vec<((usize, Vec<usize>), bool)> {
+        fn collect(app: &App, task: &Task, idx: usize, path: &mut Vec<usize>, items: &mut Vec<((usize, Vec<usize>), bool)>) {
+            items.push(((idx, path.clone()), task.matches_search(&app.search_query)));
+            for sub_idx in app.listed(&task.subtasks) {
+                path.push(sub_idx);
+                collect(app, &task.subtasks[sub_idx], idx, path, items);
+                path.pop();
+            }
+        }
+
+        let mut items = Vec::new();
+        if self.search_query.is_empty() {
+            return items;
+        }
+        for idx in self.listed(&self.tasks) {
+            collect(self, &self.tasks[idx], idx, &mut Vec::new(), &mut items);
+        }
+        items
+    }
+
+    /// The first match in list order counting from the selection, wrapping
+    /// around. The selection itself is only considered unless `skip_current`.
+    fn find_search_match(&self, skip_current: bool, backwards: bool) -> Option<(usize, Vec<usize>)> {
+        let items = self.search_candidates();
+        let len = items.len();
+        let current = (self.selected_index, self.selected_path.clone());
+        let start = items.iter().position(|(item, _)| *item == current).unwrap_or(0);
+        let offsets = if skip_current { 1..len + 1 } else { 0..len };
+        offsets
+            .map(|offset| if backwards { (start + len - offset % len) % len } else { (start + offset) % len })
+            .find(|&i| items[i].1)
+            .map(|i| items[i].0.clone())
+    }
+
+    /// Updates the search as it's typed, moving to the first match at or
+    /// below the selection. Returns false if nothing matches.
+    pub fn set_search_query(&mut self, query: &str) -> bool {
+        self.search_query = query.to_string();
+        let found = self.find_search_match(false, false);
+        self.select_match(found)
+    }
+
+    /// Moves to the next match after the selection, wrapping to the first.
+    pub fn next_search_match(&mut self) -> bool {
+        let found = self.find_search_match(true, false);
+        self.select_match(found)
+    }
+
+    /// Moves to the match before the selection, wrapping to the last.
+    pub fn previous_search_match(&mut self) -> bool {
+        let found = self.find_search_match(true, true);
+        self.select_match(found)
+    }
+
+    pub fn clear_search(&mut self) {
+        self.search_query.clear();
+    }
+
+    /// Selects a match, unfolding its parents so it's visible.
+    fn select_match(&mut self, found: Option<(usize, Vec<usize>)>) -> bool {
+        let Some((task_idx, path)) = found else {
+            return false;
+        };
+        let mut task = &mut self.tasks[task_idx];
+        for &idx in &path {
+            task.collapsed = false;
+            task = &mut task.subtasks[idx];
+        }
+        self.selected_index = task_idx;
+        self.selected_path = path;
+        true
+    }
+
     pub fn get_selected_parent_id(&self) -> Option<usize> {
         self.get_task_at_path(&self.selected_path).map(|t| t.id)
     }
 
     pub fn delete_selected_task(&mut self) {
+        // Nothing is listed when the filter hides every task
+        if !self.is_selected_shown() {
+            return;
+        }
         if self.selected_path.is_empty() {
             if !self.tasks.is_empty() {
                 self.tasks.remove(self.selected_index);
@@ -520,17 +1821,55 @@ impl App {
                 self.validate_selected_index();
             }
         }
+        if self.focused_task().is_none() {
+            self.focused_task_id = None;
+        }
+        self.select_shown_row();
     }
 
     pub fn clear_all_tasks(&mut self) {
         self.tasks.clear();
+        self.focused_task_id = None;
         self.selected_index = 0;
         self.selected_path.clear();
     }
 }
 
+const STATE_VERSION: u32 = 2;
+
 #[derive(Debug, Serialize, Deserialize)]
 pub(crate) struct SavedState {
+    version: u32,
+    tasks: Vec<Task>,
+    pomodoro: SavedPomodoro,
+    next_task_id: usize,
+    theme: Option<String>,
+    #[serde(default)]
+    focused_task_id: Option<usize>,
+    #[serde(default)]
+    sort_by_priority: bool,
+    #[serde(default)]
+    tag_filter: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SavedPomodoro {
+    settings: PomodoroSettings,
+    state: PomodoroState,
+    timer_state: TimerState,
+    cycles: usize,
+    duration_seconds: i64,
+    remaining_seconds: i64,
+    phase_started_at: Option<DateTime<Local>>,
+    /// When the phase was due to end, if it was running at save time.
+    #[serde(default)]
+    deadline: Option<DateTime<Local>>,
+}
+
+/// Version 1 of `state.json`, which stored the Pomodoro phase as a string and
+/// left its length to be inferred from the remaining time.
+#[derive(Debug, Deserialize)]
+struct SavedStateV1 {
     tasks: Vec<Task>,
     pomodoro_cycles: usize,
     pomodoro_state: String,
@@ -538,6 +1877,64 @@ pub(crate) struct SavedState {
     pomodoro_remaining_seconds: i64,
     next_task_id: usize,
     theme: Option<String>,
+    #[serde(default)]
+    pomodoro_settings: Option<PomodoroSettings>,
+}
+
+impl From<SavedStateV1> for SavedState {
+    fn from(old: SavedStateV1) -> Self {
+        let settings = old.pomodoro_settings.unwrap_or_default();
+        let state = match old.pomodoro_state.as_str() {
+            "Work" => PomodoroState::Work,
+            "ShortBreak" => PomodoroState::ShortBreak,
+            "LongBreak" => PomodoroState::LongBreak,
+            _ => {
+                eprintln!("Warning: Invalid Pomodoro state '{}', defaulting to Work", old.pomodoro_state);
+                PomodoroState::Work
+            },
+        };
+        let timer_state = match old.pomodoro_timer_state.as_str() {
+            "Stopped" => TimerState::Stopped,
+            // Version 1 never recorded when a running timer started
+            "Running" | "Paused" => TimerState::Paused,
+            _ => {
+                eprintln!("Warning: Invalid timer state '{}', defaulting to Stopped", old.pomodoro_timer_state);
+                TimerState::Stopped
+            },
+        };
+        SavedState {
+            version: STATE_VERSION,
+            tasks: old.tasks,
+            pomodoro: SavedPomodoro {
+                settings,
+                state,
+                timer_state,
+                cycles: old.pomodoro_cycles,
+                duration_seconds: settings.duration_for(state).num_seconds(),
+                remaining_seconds: old.pomodoro_remaining_seconds,
+                phase_started_at: None,
+                deadline: None,
+            },
+            next_task_id: old.next_task_id,
+            theme: old.theme,
+            focused_task_id: None,
+            sort_by_priority: false,
+            tag_filter: Vec::new(),
+        }
+    }
+}
+
+impl SavedState {
+    /// Parses any known version of `state.json`, migrating older formats.
+    fn from_json(json: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let value: serde_json::Value = serde_json::from_str(json)?;
+        let version = value.get("version").and_then(|v| v.as_u64()).unwrap_or(1);
+        match version {
+            1 => Ok(serde_json::from_value::<SavedStateV1>(value)?.into()),
+            2 => Ok(serde_json::from_value(value)?),
+            _ => Err(format!("Unsupported state version {}", version).into()),
+        }
+    }
 }
 
 impl App {
@@ -547,23 +1944,67 @@ impl App {
             .join("tui_pomo");
         std::fs::create_dir_all(&config_dir)?;
 
-        let state = SavedState {
+        let json = serde_json::to_string_pretty(&self.saved_state())?;
+        std::fs::write(config_dir.join("state.json"), json)?;
+        Ok(())
+    }
+
+    fn saved_state(&self) -> SavedState {
+        SavedState {
+            version: STATE_VERSION,
             tasks: self.tasks.clone(),
-            pomodoro_cycles: self.pomodoro.cycles,
-            pomodoro_state: format!("{:?}", self.pomodoro.state),
-            pomodoro_timer_state: format!("{:?}", self.pomodoro.timer_state),
-            pomodoro_remaining_seconds: self.pomodoro.get_remaining_seconds(),
+            pomodoro: SavedPomodoro {
+                settings: self.pomodoro.settings,
+                state: self.pomodoro.state,
+                timer_state: self.pomodoro.timer_state,
+                cycles: self.pomodoro.cycles,
+                duration_seconds: self.pomodoro.duration.num_seconds(),
+                remaining_seconds: self.pomodoro.get_remaining_seconds(),
+                phase_started_at: self.pomodoro.phase_started_at,
+                deadline: self.pomodoro.deadline(),
+            },
             next_task_id: self.next_task_id,
             theme: Some(format!("{:?}", self.theme_name)),
-        };
+            focused_task_id: self.focused_task_id,
+            sort_by_priority: self.sort_by_priority,
+            tag_filter: self.tag_filter.clone(),
+        }
+    }
 
-        let json = serde_json::to_string_pretty(&state)?;
-        std::fs::write(config_dir.join("state.json"), json)?;
+    pub fn load_history(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let mut history = crate::history::load_history()?;
+        // Sessions recorded while restoring state come after the saved ones
+        history.append(&mut self.history);
+        self.history = history;
+        Ok(())
+    }
+
+    /// Appends sessions recorded since the last call to the history file,
+    /// and rewrites the saved ones changed since.
+    pub fn save_history(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        if !self.amended_sessions.is_empty() {
+            crate::history::amend_history(&self.amended_sessions)?;
+            self.amended_sessions.clear();
+        }
+        if self.unsaved_sessions == 0 {
+            return Ok(());
+        }
+        let start = self.history.len() - self.unsaved_sessions;
+        crate::history::append_history(&self.history[start..])?;
+        self.unsaved_sessions = 0;
         Ok(())
     }
 
     pub fn show_save_notification(&mut self) {
-        self.save_notification_time = Some(std::time::Instant::now());
+        self.show_notification("Saved".to_string(), std::time::Duration::from_secs(1));
+    }
+
+    pub fn show_notification(&mut self, message: String, duration: std::time::Duration) {
+        self.notification = Some(Notification {
+            message,
+            shown_at: std::time::Instant::now(),
+            duration,
+        });
     }
 
     pub fn save_tasks_to_txt(&self) -> Result<(), Box<dyn std::error::Error>> {
@@ -628,67 +2069,72 @@ impl App {
         let json = std::fs::read_to_string(&state_file)
             .map_err(|e| format!("Failed to read state file: {}. Error: {}", state_file.display(), e))?;
         
-        let state: SavedState = serde_json::from_str(&json)
-            .map_err(|e| format!("Failed to parse state file (invalid JSON): {}. Error: {}", state_file.display(), e))?;
+        let state = SavedState::from_json(&json)
+            .map_err(|e| format!("Failed to parse state file: {}. Error: {}", state_file.display(), e))?;
+        self.restore_state(state);
+        Ok(())
+    }
 
+    fn restore_state(&mut self, state: SavedState) {
         // Restore tasks
         self.tasks = state.tasks;
-        
-        // Restore Pomodoro state
-        self.pomodoro.cycles = state.pomodoro_cycles;
-        self.pomodoro.state = match state.pomodoro_state.as_str() {
-            "Work" => PomodoroState::Work,
-            "ShortBreak" => PomodoroState::ShortBreak,
-            "LongBreak" => PomodoroState::LongBreak,
-            _ => {
-                eprintln!("Warning: Invalid Pomodoro state '{}', defaulting to Work", state.pomodoro_state);
-                PomodoroState::Work
-            },
+        self.focused_task_id = state.focused_task_id.filter(|&id| find_task_by_id(&self.tasks, id).is_some());
+        self.sort_by_priority = state.sort_by_priority;
+        self.tag_filter = state.tag_filter;
+
+        // Restore Pomodoro state exactly as saved
+        let saved = state.pomodoro;
+        let timer = &mut self.pomodoro;
+        timer.settings = saved.settings;
+        for name in timer.settings.fix_zeros() {
+            eprintln!("Warning: Invalid '{}' of 0, using the default", name);
+        }
+        timer.state = saved.state;
+        timer.timer_state = saved.timer_state;
+        timer.cycles = saved.cycles;
+        timer.phase_started_at = saved.phase_started_at;
+        timer.duration = if saved.duration_seconds > 0 {
+            Duration::seconds(saved.duration_seconds)
+        } else {
+            eprintln!("Warning: Invalid duration '{}', using configured length", saved.duration_seconds);
+            timer.settings.duration_for(timer.state)
         };
-        
-        // Restore timer state
-        self.pomodoro.timer_state = match state.pomodoro_timer_state.as_str() {
-            "Stopped" => TimerState::Stopped,
-            "Running" => TimerState::Running,
-            "Paused" => TimerState::Paused,
-            _ => {
-                eprintln!("Warning: Invalid timer state '{}', defaulting to Stopped", state.pomodoro_timer_state);
-                TimerState::Stopped
-            },
+
+        // Restore remaining time; open-ended phases count on past zero
+        let valid_remaining = if timer.is_open_ended() {
+            saved.remaining_seconds <= timer.duration.num_seconds()
+        } else {
+            (0..=timer.duration.num_seconds()).contains(&saved.remaining_seconds)
         };
-        
-        // Restore remaining time
-        if state.pomodoro_remaining_seconds >= 0 {
-            self.pomodoro.remaining = Duration::seconds(state.pomodoro_remaining_seconds);
+        if valid_remaining {
+            timer.remaining = Duration::seconds(saved.remaining_seconds);
+            timer.overtime_alerted = saved.remaining_seconds < 0;
+            timer.start_time = match timer.timer_state {
+                TimerState::Running => Some(Local::now()),
+                _ => None,
+            };
         } else {
-            eprintln!("Warning: Invalid remaining time '{}', resetting timer", state.pomodoro_remaining_seconds);
-            self.pomodoro.reset();
+            eprintln!("Warning: Invalid remaining time '{}', resetting timer", saved.remaining_seconds);
+            timer.reset();
         }
-        
-        // Infer duration and state from remaining time if it matches a known duration
-        // This fixes cases where state and remaining time don't match
-        let remaining_minutes = self.pomodoro.remaining.num_minutes();
-        match remaining_minutes {
-            25 => {
-                self.pomodoro.duration = Duration::minutes(25);
-                self.pomodoro.state = PomodoroState::Work;
-            }
-            5 => {
-                self.pomodoro.duration = Duration::minutes(5);
-                self.pomodoro.state = PomodoroState::ShortBreak;
-            }
-            15 => {
-                self.pomodoro.duration = Duration::minutes(15);
-                self.pomodoro.state = PomodoroState::LongBreak;
-            }
-            _ => {
-                // If remaining doesn't match a known duration, sync based on current state
-                self.pomodoro.sync_duration_with_state();
-                // Then ensure state matches duration (in case there was a mismatch)
-                self.pomodoro.sync_state_with_duration();
+
+        // A running timer keeps counting while the app is closed
+        if timer.timer_state == TimerState::Running {
+            match saved.deadline {
+                Some(deadline) => {
+                    let missed = timer.resume_until(deadline, Local::now());
+                    for phase in &missed {
+                        self.record_session(phase, SessionOutcome::Completed);
+                    }
+                    if !missed.is_empty() {
+                        self.emit_auto_start();
+                    }
+                    self.report_missed_phases(&missed);
+                }
+                None => timer.timer_state = TimerState::Paused,
             }
         }
-        
+
         // Restore next_task_id with validation
         if state.next_task_id == 0 {
             eprintln!("Warning: Invalid next_task_id '{}', defaulting to 1", state.next_task_id);
@@ -721,10 +2167,20 @@ impl App {
 
         // Validate and fix selected_index
         self.validate_selected_index();
-
-        Ok(())
     }
     
+    fn report_missed_phases(&mut self, missed: &[CompletedPhase]) {
+        if missed.is_empty() {
+            return;
+        }
+        let message = missed
+            .iter()
+            .map(|phase| format!("{:?} ended at {} while tsk was closed", phase.state, phase.ended_at.format("%H:%M")))
+            .collect::<Vec<_>>()
+            .join("\n");
+        self.show_notification(message, std::time::Duration::from_secs(5));
+    }
+
     fn validate_selected_index(&mut self) {
         if self.tasks.is_empty() {
             self.selected_index = 0;
@@ -733,6 +2189,7 @@ impl App {
             self.selected_index = self.tasks.len() - 1;
             self.selected_path.clear();
         }
+        self.select_shown_row();
     }
 }
 
@@ -743,7 +2200,7 @@ mod tests {
     #[test]
     fn test_add_task() {
         let mut app = App::new();
-        assert!(app.add_task("Test Task".to_string()));
+        assert!(app.add_task("Test Task".to_string()).is_ok());
         assert_eq!(app.tasks.len(), 1);
         assert_eq!(app.tasks[0].title, "Test Task");
     }
@@ -751,23 +2208,84 @@ mod tests {
     #[test]
     fn test_add_task_empty() {
         let mut app = App::new();
-        assert!(!app.add_task("   ".to_string()));
+        assert!(app.add_task("   ".to_string()).is_err());
         assert_eq!(app.tasks.len(), 0);
     }
 
+    #[test]
+    fn test_add_task_quick_add() {
+        let mut app = App::new();
+        assert!(app.add_task("Report #work !urgent ~2 due:2026-11-02".to_string()).is_ok());
+        app.add_subtask(1, "Outline #draft".to_string()).unwrap();
+        let task = &app.tasks[0];
+        assert_eq!(task.title, "Report");
+        assert_eq!(task.tags, vec!["work"]);
+        assert_eq!(task.priority, Priority::Urgent);
+        assert_eq!(task.estimate, Some(2));
+        assert_eq!(task.due, NaiveDate::from_ymd_opt(2026, 11, 2));
+        assert_eq!(task.subtasks[0].tags, vec!["draft"]);
+
+        assert_eq!(app.add_task("Slides ~0".to_string()), Err("Invalid estimate '~0', use a number of pomodoros like ~3".to_string()));
+        assert_eq!(app.add_task("#work !high".to_string()), Err("Task title is empty".to_string()));
+        assert_eq!(app.tasks.len(), 1);
+        assert_eq!(app.next_task_id, 3);
+    }
+
     #[test]
     fn test_add_task_long_title() {
         let mut app = App::new();
         let long_title = "a".repeat(300);
-        assert!(app.add_task(long_title.clone()));
+        assert!(app.add_task(long_title.clone()).is_ok());
         assert_eq!(app.tasks.len(), 1);
         assert_eq!(app.tasks[0].title.len(), 200);
     }
 
+    #[test]
+    fn test_add_task_multibyte_title() {
+        let mut app = App::new();
+        // Byte 200 falls inside the emoji
+        let title = format!("{}🍅{}", "a".repeat(199), "b".repeat(10));
+        assert!(app.add_task(title).is_ok());
+        assert_eq!(app.tasks[0].title, format!("{}🍅", "a".repeat(199)));
+
+        app.max_title_length = 3;
+        app.start_input(InputMode::AddingTask, "日本語テキスト".to_string());
+        assert!(app.is_input_truncated());
+        assert!(app.add_task("日本語テキスト".to_string()).is_ok());
+        assert_eq!(app.tasks[1].title, "日本語");
+
+        // Tokens are taken out before the title is measured
+        app.max_title_length = 10;
+        app.start_input(InputMode::AddingTask, "Short task #work !high".to_string());
+        assert!(!app.is_input_truncated());
+        app.start_input(InputMode::AddingTask, "Short task ~two #".to_string());
+        assert!(app.is_input_truncated());
+    }
+
+    #[test]
+    fn test_find_ignoring_case() {
+        assert_eq!(find_ignoring_case("Write Report", "report"), Some((6, 12)));
+        assert_eq!(find_ignoring_case("Write Report", "memo"), None);
+        assert_eq!(find_ignoring_case("Write Report", ""), None);
+        // İ lowercases to two chars and ẞ to a shorter one
+        assert_eq!(find_ignoring_case("İẞx", "ß"), Some((2, 5)));
+        assert_eq!(find_ignoring_case("İẞx", "i"), Some((0, 2)));
+        assert_eq!(find_ignoring_case("İẞx", "X"), Some((5, 6)));
+    }
+
+    #[test]
+    fn test_truncate_title_graphemes() {
+        // Flag and family emoji are single graphemes made of several chars
+        assert_eq!(truncate_title("🇳🇿👨‍👩‍👧x", 2), "🇳🇿👨‍👩‍👧");
+        assert_eq!(truncate_title("e\u{301}te\u{301}", 1), "e\u{301}");
+        assert_eq!(truncate_title("one two", 4), "one");
+        assert_eq!(truncate_title("short", 10), "short");
+    }
+
     #[test]
     fn test_toggle_task_completion() {
         let mut app = App::new();
-        app.add_task("Test Task".to_string());
+        app.add_task("Test Task".to_string()).unwrap();
         assert!(!app.tasks[0].completed);
         
         app.toggle_task_completion();
@@ -780,10 +2298,10 @@ mod tests {
     #[test]
     fn test_toggle_task_completion_with_subtasks() {
         let mut app = App::new();
-        app.add_task("Parent Task".to_string());
+        app.add_task("Parent Task".to_string()).unwrap();
         app.selected_index = 0;
         app.selected_path = vec![];
-        app.add_subtask(1, "Subtask".to_string());
+        app.add_subtask(1, "Subtask".to_string()).unwrap();
         
         // Toggle parent task
         app.selected_index = 0;
@@ -797,8 +2315,8 @@ mod tests {
     #[test]
     fn test_delete_task() {
         let mut app = App::new();
-        app.add_task("Task 1".to_string());
-        app.add_task("Task 2".to_string());
+        app.add_task("Task 1".to_string()).unwrap();
+        app.add_task("Task 2".to_string()).unwrap();
         app.selected_index = 0;
         
         app.delete_selected_task();
@@ -809,8 +2327,8 @@ mod tests {
     #[test]
     fn test_clear_all_tasks() {
         let mut app = App::new();
-        app.add_task("Task 1".to_string());
-        app.add_task("Task 2".to_string());
+        app.add_task("Task 1".to_string()).unwrap();
+        app.add_task("Task 2".to_string()).unwrap();
         
         app.clear_all_tasks();
         assert_eq!(app.tasks.len(), 0);
@@ -820,17 +2338,20 @@ mod tests {
     #[test]
     fn test_add_subtask_max_depth() {
         let mut app = App::new();
-        app.add_task("Level 0".to_string());
+        app.max_depth = Some(3);
+        app.add_task("Level 0".to_string()).unwrap();
+        app.add_subtask(1, "Level 1".to_string()).unwrap();
         app.selected_path = vec![0];
-        app.add_subtask(1, "Level 1".to_string());
+        app.add_subtask(2, "Level 2".to_string()).unwrap();
         app.selected_path = vec![0, 0];
-        app.add_subtask(2, "Level 2".to_string());
+        app.add_subtask(3, "Level 3".to_string()).unwrap();
         app.selected_path = vec![0, 0, 0];
-        app.add_subtask(3, "Level 3".to_string());
-        app.selected_path = vec![0, 0, 0, 0];
         
         // Should fail at level 4
-        assert!(!app.add_subtask(4, "Level 4".to_string()));
+        assert_eq!(
+            app.add_subtask(4, "Level 4".to_string()),
+            Err("Subtasks can't be nested more than 3 levels deep".to_string())
+        );
     }
 
     #[test]
@@ -866,6 +2387,867 @@ mod tests {
         assert_eq!(timer.duration, Duration::minutes(15));
     }
 
+    #[test]
+    fn test_pomodoro_custom_settings() {
+        let mut timer = PomodoroTimer::new();
+        timer.set_settings(PomodoroSettings {
+            work_minutes: 50,
+            short_break_minutes: 10,
+            long_break_minutes: 20,
+            long_break_interval: 2,
+            ..PomodoroSettings::default()
+        });
+        assert_eq!(timer.remaining, Duration::minutes(50));
+
+        timer.advance_cycle();
+        assert_eq!(timer.state, PomodoroState::ShortBreak);
+        assert_eq!(timer.duration, Duration::minutes(10));
+
+        timer.advance_cycle();
+        timer.advance_cycle();
+        assert_eq!(timer.state, PomodoroState::LongBreak);
+        assert_eq!(timer.duration, Duration::minutes(20));
+    }
+
+    #[test]
+    fn test_load_state_migrates_v1() {
+        // A paused work session with exactly five minutes left
+        let json = r#"{
+            "tasks": [],
+            "pomodoro_cycles": 2,
+            "pomodoro_state": "Work",
+            "pomodoro_timer_state": "Paused",
+            "pomodoro_remaining_seconds": 300,
+            "next_task_id": 1,
+            "theme": "Dark"
+        }"#;
+        let mut app = App::new();
+        app.restore_state(SavedState::from_json(json).unwrap());
+        assert_eq!(app.pomodoro.state, PomodoroState::Work);
+        assert_eq!(app.pomodoro.timer_state, TimerState::Paused);
+        assert_eq!(app.pomodoro.duration, Duration::minutes(25));
+        assert_eq!(app.pomodoro.remaining, Duration::minutes(5));
+        assert_eq!(app.pomodoro.cycles, 2);
+        assert_eq!(app.theme_name, ThemeName::Dark);
+    }
+
+    #[test]
+    fn test_save_state_round_trip() {
+        let mut app = App::new();
+        app.add_task("Task".to_string()).unwrap();
+        app.pomodoro.state = PomodoroState::ShortBreak;
+        app.pomodoro.duration = Duration::minutes(5);
+        app.pomodoro.remaining = Duration::minutes(3);
+        app.pomodoro.timer_state = TimerState::Paused;
+        app.pomodoro.phase_started_at = Some(Local::now());
+
+        let json = serde_json::to_string(&app.saved_state()).unwrap();
+        let mut restored = App::new();
+        restored.restore_state(SavedState::from_json(&json).unwrap());
+        assert_eq!(restored.tasks.len(), 1);
+        assert_eq!(restored.pomodoro.state, PomodoroState::ShortBreak);
+        assert_eq!(restored.pomodoro.timer_state, TimerState::Paused);
+        assert_eq!(restored.pomodoro.remaining, Duration::minutes(3));
+        assert_eq!(restored.pomodoro.phase_started_at, app.pomodoro.phase_started_at);
+    }
+
+    #[test]
+    fn test_save_state_round_trip_overtime() {
+        let mut app = App::new();
+        app.pomodoro.settings.mode = TimerMode::Overtime;
+        app.pomodoro.phase_started_at = Some(Local::now() - Duration::minutes(25));
+        app.pomodoro.remaining = Duration::seconds(-30);
+        app.pomodoro.timer_state = TimerState::Paused;
+
+        let json = serde_json::to_string(&app.saved_state()).unwrap();
+        let mut restored = App::new();
+        restored.restore_state(SavedState::from_json(&json).unwrap());
+        assert_eq!(restored.pomodoro.state, PomodoroState::Work);
+        assert_eq!(restored.pomodoro.timer_state, TimerState::Paused);
+        assert_eq!(restored.pomodoro.remaining, Duration::seconds(-30));
+        assert_eq!(restored.pomodoro.phase_started_at, app.pomodoro.phase_started_at);
+        assert!(!restored.pomodoro.take_overtime_alert());
+
+        // A classic phase can't be past zero
+        app.pomodoro.settings.mode = TimerMode::Classic;
+        let json = serde_json::to_string(&app.saved_state()).unwrap();
+        let mut restored = App::new();
+        restored.restore_state(SavedState::from_json(&json).unwrap());
+        assert_eq!(restored.pomodoro.remaining, restored.pomodoro.duration);
+    }
+
+    #[test]
+    fn test_resume_until_future_deadline() {
+        let mut timer = PomodoroTimer::new();
+        let now = Local::now();
+        timer.timer_state = TimerState::Running;
+        let missed = timer.resume_until(now + Duration::minutes(10), now);
+        assert!(missed.is_empty());
+        assert_eq!(timer.timer_state, TimerState::Running);
+        assert_eq!(timer.get_remaining_seconds() / 60, 9);
+    }
+
+    #[test]
+    fn test_resume_until_past_deadline() {
+        let mut timer = PomodoroTimer::new();
+        let now = Local::now();
+        let started = now - Duration::minutes(40);
+        timer.timer_state = TimerState::Running;
+        timer.phase_started_at = Some(started);
+        let missed = timer.resume_until(started + Duration::minutes(25), now);
+        assert_eq!(missed.len(), 1);
+        assert_eq!(missed[0].state, PomodoroState::Work);
+        assert_eq!(missed[0].ended_at, started + Duration::minutes(25));
+        assert_eq!(timer.state, PomodoroState::ShortBreak);
+        assert_eq!(timer.timer_state, TimerState::Stopped);
+        assert_eq!(timer.cycles, 1);
+    }
+
+    #[test]
+    fn test_reset_records_abandoned_session() {
+        let mut app = App::new();
+        app.add_task("Focus".to_string()).unwrap();
+        app.reset_pomodoro();
+        assert!(app.history.is_empty());
+
+        app.pomodoro.start();
+        app.reset_pomodoro();
+        assert_eq!(app.history.len(), 1);
+        assert_eq!(app.history[0].phase, PomodoroState::Work);
+        assert_eq!(app.history[0].outcome, SessionOutcome::Reset);
+        assert_eq!(app.history[0].task_title.as_deref(), Some("Focus"));
+    }
+
+    #[test]
+    fn test_update_records_completed_session() {
+        let mut app = App::new();
+        app.pomodoro.start();
+        app.pomodoro.start_time = Some(Local::now() - Duration::minutes(26));
+        assert!(app.update_pomodoro().is_some());
+        assert_eq!(app.history.len(), 1);
+        assert_eq!(app.history[0].outcome, SessionOutcome::Completed);
+        assert_eq!(app.history[0].task_id, None);
+        assert_eq!(app.pomodoro.state, PomodoroState::ShortBreak);
+    }
+
+    #[test]
+    fn test_focused_task_is_credited() {
+        let mut app = App::new();
+        app.add_task("Parent".to_string()).unwrap();
+        app.add_subtask(1, "Child".to_string()).unwrap();
+        app.selected_path = vec![0];
+        app.toggle_focus();
+        assert_eq!(app.focused_task_id, Some(2));
+
+        // Selection moving away doesn't change the credited task
+        app.selected_path = vec![];
+        app.pomodoro.start();
+        app.pomodoro.start_time = Some(Local::now() - Duration::minutes(26));
+        app.pomodoro.phase_started_at = app.pomodoro.start_time;
+        assert!(app.update_pomodoro().is_some());
+
+        let child = &app.tasks[0].subtasks[0];
+        assert_eq!(child.pomodoros, 1);
+        assert_eq!(child.focus_seconds, 25 * 60);
+        assert_eq!(app.tasks[0].total_pomodoros(), 1);
+        assert_eq!(app.tasks[0].total_focus_seconds(), 25 * 60);
+        assert_eq!(app.history[0].task_id, Some(2));
+
+        // Breaks are not credited
+        app.pomodoro.start();
+        app.reset_pomodoro();
+        assert_eq!(app.tasks[0].subtasks[0].focus_seconds, 25 * 60);
+    }
+
+    #[test]
+    fn test_paused_time_is_not_credited() {
+        let mut app = App::new();
+        app.add_task("Task".to_string()).unwrap();
+        app.toggle_focus();
+        app.pomodoro.start();
+        app.pomodoro.start_time = Some(Local::now() - Duration::minutes(10));
+        app.pomodoro.phase_started_at = Some(Local::now() - Duration::hours(3));
+        app.pomodoro.pause();
+
+        // Resumed after a long pause, the last 15 minutes finish the phase
+        app.pomodoro.start();
+        app.pomodoro.start_time = Some(Local::now() - Duration::minutes(16));
+        let phase = app.update_pomodoro().unwrap();
+        assert_eq!(phase.active_seconds, 25 * 60);
+        assert_eq!(app.tasks[0].focus_seconds, 25 * 60);
+        assert_eq!(app.history[0].active_seconds, Some(25 * 60));
+        assert!((app.history[0].ended_at - app.history[0].started_at).num_hours() >= 2);
+    }
+
+    #[test]
+    fn test_deleting_focused_task_clears_focus() {
+        let mut app = App::new();
+        app.add_task("Task".to_string()).unwrap();
+        app.toggle_focus();
+        assert_eq!(app.focused_task_id, Some(1));
+        app.delete_selected_task();
+        assert_eq!(app.focused_task_id, None);
+    }
+
+    #[test]
+    fn test_set_selected_estimate() {
+        let mut app = App::new();
+        app.add_task("Task".to_string()).unwrap();
+        assert!(app.set_selected_estimate(" 4 ").is_ok());
+        assert_eq!(app.get_selected_estimate(), Some(4));
+        assert_eq!(
+            app.set_selected_estimate("four"),
+            Err("Invalid estimate 'four', use a number of pomodoros".to_string())
+        );
+        assert_eq!(app.get_selected_estimate(), Some(4));
+        assert!(app.set_selected_estimate("").is_ok());
+        assert_eq!(app.get_selected_estimate(), None);
+    }
+
+    #[test]
+    fn test_events_are_queued() {
+        let mut app = App::new();
+        app.add_task("Task".to_string()).unwrap();
+        app.toggle_pomodoro();
+        app.toggle_pomodoro();
+        // Resuming a paused phase isn't a new start
+        app.toggle_pomodoro();
+        app.toggle_task_completion();
+        app.toggle_task_completion();
+        app.reset_pomodoro();
+
+        let events: Vec<AppEventKind> = app.take_events().iter().map(|e| e.event).collect();
+        assert_eq!(events, vec![
+            AppEventKind::TaskAdded,
+            AppEventKind::WorkStart,
+            AppEventKind::TaskCompleted,
+            AppEventKind::WorkEnd,
+        ]);
+        assert!(app.take_events().is_empty());
+    }
+
+    #[test]
+    fn test_auto_start_policies() {
+        let mut timer = PomodoroTimer::new();
+        timer.policy.auto_start_breaks = true;
+        timer.start();
+        timer.start_time = Some(Local::now() - Duration::minutes(26));
+        assert!(timer.update());
+        assert_eq!(timer.state, PomodoroState::ShortBreak);
+        assert_eq!(timer.timer_state, TimerState::Running);
+
+        // Work isn't started automatically after the break
+        timer.start_time = Some(Local::now() - Duration::minutes(6));
+        assert!(timer.update());
+        assert_eq!(timer.state, PomodoroState::Work);
+        assert_eq!(timer.timer_state, TimerState::Stopped);
+    }
+
+    #[test]
+    fn test_resume_until_with_auto_start() {
+        let mut timer = PomodoroTimer::new();
+        timer.policy.auto_start_breaks = true;
+        timer.policy.auto_start_work = true;
+        let started = Local::now() - Duration::minutes(40);
+        timer.timer_state = TimerState::Running;
+        timer.phase_started_at = Some(started);
+        let missed = timer.resume_until(started + Duration::minutes(25), Local::now());
+        // Work and the short break both ended; the next work session is running
+        assert_eq!(missed.len(), 2);
+        assert_eq!(missed[1].state, PomodoroState::ShortBreak);
+        assert_eq!(missed[1].started_at, started + Duration::minutes(25));
+        assert_eq!(timer.state, PomodoroState::Work);
+        assert_eq!(timer.timer_state, TimerState::Running);
+        assert_eq!(timer.get_remaining_seconds() / 60, 14);
+    }
+
+    #[test]
+    fn test_resume_until_stops_catching_up() {
+        let mut timer = PomodoroTimer::new();
+        timer.policy.auto_start_breaks = true;
+        timer.policy.auto_start_work = true;
+        let now = Local::now();
+        let started = now - Duration::days(30);
+        timer.timer_state = TimerState::Running;
+        timer.phase_started_at = Some(started);
+        let missed = timer.resume_until(started + Duration::minutes(25), now);
+        assert_eq!(missed.len(), MAX_MISSED_PHASES);
+        assert_eq!(timer.timer_state, TimerState::Stopped);
+        assert_eq!(timer.remaining, timer.duration);
+        assert_eq!(timer.phase_started_at, None);
+    }
+
+    #[test]
+    fn test_restore_state_replaces_zero_lengths() {
+        let mut app = App::new();
+        app.pomodoro.settings.short_break_minutes = 0;
+        app.pomodoro.settings.long_break_interval = 0;
+        app.pomodoro.policy.auto_start_breaks = true;
+        app.pomodoro.policy.auto_start_work = true;
+        app.pomodoro.start();
+        let json = serde_json::to_string(&app.saved_state()).unwrap();
+
+        let mut restored = App::new();
+        restored.pomodoro.policy = app.pomodoro.policy;
+        restored.restore_state(SavedState::from_json(&json).unwrap());
+        assert_eq!(restored.pomodoro.settings.short_break_minutes, 5);
+        assert_eq!(restored.pomodoro.settings.long_break_interval, 4);
+        assert_eq!(restored.pomodoro.settings.work_minutes, 25);
+    }
+
+    #[test]
+    fn test_break_reminder() {
+        let mut timer = PomodoroTimer::new();
+        timer.policy.break_reminder_minutes = Some(2);
+        timer.start();
+        timer.start_time = Some(Local::now() - Duration::minutes(26));
+        assert!(timer.update());
+
+        let now = Local::now();
+        assert!(!timer.break_reminder_due(now));
+        assert!(timer.break_reminder_due(now + Duration::minutes(3)));
+        assert!(!timer.break_reminder_due(now + Duration::minutes(4)));
+        assert!(timer.break_reminder_due(now + Duration::minutes(5)));
+
+        timer.start();
+        assert!(!timer.break_reminder_due(now + Duration::minutes(10)));
+    }
+
+    #[test]
+    fn test_overtime_mode() {
+        let mut app = App::new();
+        app.pomodoro.settings.mode = TimerMode::Overtime;
+        app.pomodoro.start();
+        app.pomodoro.start_time = Some(Local::now() - Duration::minutes(30));
+        app.pomodoro.phase_started_at = app.pomodoro.start_time;
+
+        assert!(app.update_pomodoro().is_none());
+        assert_eq!(app.pomodoro.state, PomodoroState::Work);
+        assert!(app.pomodoro.is_in_overtime());
+        assert_eq!(app.pomodoro.get_remaining_seconds() / 60, -5);
+        assert!(app.pomodoro.take_overtime_alert());
+        assert!(!app.pomodoro.take_overtime_alert());
+
+        let phase = app.end_pomodoro_phase().unwrap();
+        assert_eq!(phase.mode, TimerMode::Overtime);
+        assert_eq!(phase.planned_seconds, Some(25 * 60));
+        assert_eq!((phase.ended_at - phase.started_at).num_minutes(), 30);
+        assert_eq!(app.pomodoro.state, PomodoroState::ShortBreak);
+        assert_eq!(app.pomodoro.cycles, 1);
+        assert_eq!(app.history[0].mode, TimerMode::Overtime);
+    }
+
+    #[test]
+    fn test_flowtime_mode() {
+        let mut app = App::new();
+        app.pomodoro.settings.mode = TimerMode::Flowtime;
+        app.pomodoro.start();
+        app.pomodoro.start_time = Some(Local::now() - Duration::minutes(50));
+        app.pomodoro.phase_started_at = app.pomodoro.start_time;
+        assert!(app.update_pomodoro().is_none());
+        assert_eq!(app.pomodoro.get_elapsed_seconds() / 60, 50);
+
+        let phase = app.end_pomodoro_phase().unwrap();
+        assert_eq!(phase.planned_seconds, None);
+        // Break is a fifth of the time worked
+        assert_eq!(app.pomodoro.state, PomodoroState::ShortBreak);
+        assert_eq!(app.pomodoro.duration.num_minutes(), 10);
+        assert_eq!(app.history[0].planned_seconds, None);
+
+        // Breaks aren't open-ended
+        assert!(app.end_pomodoro_phase().is_none());
+
+        // Resetting the break keeps the length it earned
+        app.toggle_pomodoro();
+        app.reset_pomodoro();
+        assert_eq!(app.pomodoro.duration.num_minutes(), 10);
+        assert_eq!(app.pomodoro.remaining.num_minutes(), 10);
+
+        // Skipping started work earns a break too
+        app.skip_pomodoro_phase();
+        app.toggle_pomodoro();
+        app.pomodoro.start_time = Some(Local::now() - Duration::minutes(20));
+        app.pomodoro.phase_started_at = app.pomodoro.start_time;
+        app.skip_pomodoro_phase();
+        assert_eq!(app.pomodoro.state, PomodoroState::ShortBreak);
+        assert_eq!(app.pomodoro.duration.num_minutes(), 4);
+    }
+
+    #[test]
+    fn test_skip_phase_keeps_cycles() {
+        let mut app = App::new();
+        app.pomodoro.cycles = 3;
+        app.toggle_pomodoro();
+        app.skip_pomodoro_phase();
+        // The fourth cycle wasn't credited, so no long break yet
+        assert_eq!(app.pomodoro.cycles, 3);
+        assert_eq!(app.pomodoro.state, PomodoroState::ShortBreak);
+        assert_eq!(app.pomodoro.timer_state, TimerState::Running);
+        assert_eq!(app.history[0].outcome, SessionOutcome::Skipped);
+
+        app.skip_pomodoro_phase();
+        assert_eq!(app.pomodoro.state, PomodoroState::Work);
+        app.pomodoro.start_time = Some(Local::now() - Duration::minutes(26));
+        assert!(app.update_pomodoro().is_some());
+        assert_eq!(app.pomodoro.cycles, 4);
+        assert_eq!(app.pomodoro.state, PomodoroState::LongBreak);
+    }
+
+    #[test]
+    fn test_previous_phase() {
+        let mut timer = PomodoroTimer::new();
+        assert!(!timer.previous_phase());
+
+        timer.start();
+        timer.start_time = Some(Local::now() - Duration::minutes(26));
+        assert!(timer.update());
+        assert_eq!(timer.cycles, 1);
+        timer.skip_phase();
+        assert!(timer.previous_phase());
+        assert_eq!(timer.state, PomodoroState::ShortBreak);
+        assert_eq!(timer.cycles, 1);
+        assert!(timer.previous_phase());
+        assert_eq!(timer.state, PomodoroState::Work);
+        assert_eq!(timer.cycles, 0);
+        assert_eq!(timer.timer_state, TimerState::Stopped);
+        assert_eq!(timer.remaining, Duration::minutes(25));
+
+        // After a restart the previous phase follows the long break schedule
+        let mut timer = PomodoroTimer::new();
+        timer.cycles = 4;
+        assert!(timer.previous_phase());
+        assert_eq!(timer.state, PomodoroState::LongBreak);
+        assert_eq!(timer.cycles, 4);
+        assert!(timer.previous_phase());
+        assert_eq!(timer.state, PomodoroState::Work);
+        assert_eq!(timer.cycles, 3);
+    }
+
+    #[test]
+    fn test_previous_phase_takes_back_credit() {
+        let mut app = App::new();
+        app.add_task("Report".to_string()).unwrap();
+        app.focused_task_id = Some(app.tasks[0].id);
+        app.toggle_pomodoro();
+        app.pomodoro.start_time = Some(Local::now() - Duration::minutes(26));
+        assert!(app.update_pomodoro().is_some());
+        assert_eq!(app.tasks[0].pomodoros, 1);
+        // The completed session was already written out
+        app.unsaved_sessions = 0;
+
+        assert!(app.previous_pomodoro_phase());
+        assert_eq!(app.pomodoro.state, PomodoroState::Work);
+        assert_eq!(app.tasks[0].pomodoros, 0);
+        assert_eq!(app.history[0].outcome, SessionOutcome::Reset);
+        assert_eq!(app.amended_sessions.len(), 1);
+        assert_eq!(app.amended_sessions[0].0.outcome, SessionOutcome::Completed);
+
+        // Only a fixed number of transitions can be stepped back through
+        for _ in 0..MAX_PAST_PHASES + 10 {
+            app.pomodoro.skip_phase();
+        }
+        assert_eq!(app.pomodoro.past_phases.len(), MAX_PAST_PHASES);
+    }
+
+    #[test]
+    fn test_previous_phase_past_a_started_session() {
+        let mut app = App::new();
+        app.add_task("Report".to_string()).unwrap();
+        app.focused_task_id = Some(app.tasks[0].id);
+        app.toggle_pomodoro();
+        app.pomodoro.start_time = Some(Local::now() - Duration::minutes(26));
+        assert!(app.update_pomodoro().is_some());
+        app.skip_pomodoro_phase();
+        app.toggle_pomodoro();
+
+        // Back over the started session to the break, then to the completed work
+        assert!(app.previous_pomodoro_phase());
+        assert_eq!((app.pomodoro.state, app.pomodoro.cycles), (PomodoroState::ShortBreak, 1));
+        assert_eq!(app.tasks[0].pomodoros, 1);
+        assert!(app.previous_pomodoro_phase());
+        assert_eq!((app.pomodoro.state, app.pomodoro.cycles), (PomodoroState::Work, 0));
+        assert_eq!(app.tasks[0].pomodoros, 0);
+        let outcomes: Vec<_> = app.history.iter().map(|r| (r.phase, r.outcome)).collect();
+        assert_eq!(outcomes, vec![(PomodoroState::Work, SessionOutcome::Reset), (PomodoroState::Work, SessionOutcome::Reset)]);
+    }
+
+    #[test]
+    fn test_adjust_duration() {
+        let mut timer = PomodoroTimer::new();
+        timer.adjust_duration(Duration::minutes(5));
+        assert_eq!(timer.duration, Duration::minutes(30));
+        assert_eq!(timer.remaining, Duration::minutes(30));
+
+        timer.start();
+        timer.start_time = Some(Local::now() - Duration::minutes(10));
+        timer.adjust_duration(Duration::minutes(-25));
+        // Can't end before now; the phase finishes on the next update
+        assert_eq!(timer.duration, Duration::minutes(5));
+        assert_eq!(timer.get_remaining_seconds(), 0);
+        assert!(timer.update());
+        assert_eq!(timer.cycles, 1);
+
+        timer.adjust_duration(Duration::minutes(-10));
+        assert_eq!(timer.duration, Duration::minutes(1));
+        timer.reset();
+        assert_eq!(timer.duration, Duration::minutes(5));
+    }
+
+    #[test]
+    fn test_rename_selected_task() {
+        let mut app = App::new();
+        app.add_task("Wirte report".to_string()).unwrap();
+        app.add_subtask(1, "Outline".to_string()).unwrap();
+        assert!(app.rename_selected_task("  Write report ".to_string()).is_ok());
+        assert_eq!(app.tasks[0].title, "Write report");
+        assert_eq!(app.rename_selected_task("   ".to_string()), Err("Task title is empty".to_string()));
+
+        app.move_selection_down();
+        assert!(app.rename_selected_task("Draft outline ~2 #work".to_string()).is_ok());
+        assert_eq!(app.tasks[0].subtasks[0].title, "Draft outline");
+        assert_eq!(app.tasks[0].subtasks[0].estimate, Some(2));
+        assert_eq!(app.tasks[0].subtasks[0].tags, vec!["work"]);
+
+        // Details without a token are kept
+        assert!(app.rename_selected_task("Outline draft".to_string()).is_ok());
+        assert_eq!(app.tasks[0].subtasks[0].estimate, Some(2));
+        assert!(app.rename_selected_task("Outline ~0".to_string()).is_err());
+        assert_eq!(app.tasks[0].subtasks[0].title, "Outline draft");
+    }
+
+    #[test]
+    fn test_move_task_among_siblings() {
+        let mut app = App::new();
+        app.add_task("A".to_string()).unwrap();
+        app.add_task("B".to_string()).unwrap();
+        app.add_subtask(1, "A1".to_string()).unwrap();
+        app.add_subtask(1, "A2".to_string()).unwrap();
+
+        assert!(!app.move_task_up());
+        assert!(app.move_task_down());
+        assert_eq!(app.tasks[1].title, "A");
+        assert_eq!(app.selected_index, 1);
+
+        app.selected_path = vec![1];
+        assert!(!app.move_task_down());
+        assert!(app.move_task_up());
+        assert_eq!(app.tasks[1].subtasks[0].title, "A2");
+        assert_eq!(app.selected_path, vec![0]);
+    }
+
+    #[test]
+    fn test_indent_and_outdent() {
+        let mut app = App::new();
+        app.add_task("A".to_string()).unwrap();
+        app.add_task("B".to_string()).unwrap();
+        app.selected_index = 1;
+        app.add_subtask(2, "B1".to_string()).unwrap();
+
+        app.selected_index = 0;
+        assert!(!app.indent_task());
+        app.selected_index = 1;
+        assert!(app.indent_task());
+        assert_eq!(app.tasks.len(), 1);
+        assert_eq!(app.tasks[0].subtasks[0].title, "B");
+        assert_eq!((app.selected_index, app.selected_path.clone()), (0, vec![0]));
+        assert_eq!(app.get_task_at_path(&app.selected_path).unwrap().subtasks[0].title, "B1");
+
+        assert!(app.outdent_task());
+        assert_eq!(app.tasks[1].title, "B");
+        assert_eq!((app.selected_index, app.selected_path.clone()), (1, vec![]));
+        assert!(!app.outdent_task());
+    }
+
+    #[test]
+    fn test_indent_respects_depth_limit() {
+        let mut app = App::new();
+        app.add_task("A".to_string()).unwrap();
+        app.add_task("B".to_string()).unwrap();
+        app.selected_index = 1;
+        app.max_depth = Some(4);
+        for depth in 0..4 {
+            assert!(app.add_subtask(0, format!("B{}", depth)).is_ok());
+            app.selected_path.push(0);
+        }
+        assert!(app.add_subtask(0, "Too deep".to_string()).is_err());
+        // B already has the deepest allowed chain below it
+        app.selected_path.clear();
+        assert!(!app.indent_task());
+        assert_eq!(app.tasks.len(), 2);
+
+        app.max_depth = None;
+        assert!(app.indent_task());
+        app.selected_path = vec![0, 0, 0, 0, 0];
+        assert!(app.add_subtask(0, "Deeper".to_string()).is_ok());
+        assert_eq!(app.selected_ancestor_titles(), vec!["A", "B", "B0", "B1", "B2"]);
+    }
+
+    #[test]
+    fn test_navigation_skips_collapsed_rows() {
+        let mut app = App::new();
+        app.add_task("A".to_string()).unwrap();
+        app.add_subtask(1, "A1".to_string()).unwrap();
+        app.add_subtask(1, "A2".to_string()).unwrap();
+        app.add_task("B".to_string()).unwrap();
+
+        assert!(app.toggle_collapsed());
+        assert_eq!(app.tasks[0].subtask_progress(), (0, 2));
+        app.move_selection_down();
+        assert_eq!((app.selected_index, app.selected_path.clone()), (1, vec![]));
+        app.move_selection_down();
+        assert_eq!(app.selected_index, 1);
+        app.move_selection_up();
+        assert_eq!((app.selected_index, app.selected_path.clone()), (0, vec![]));
+
+        // Adding a subtask unfolds its parent so it can be seen
+        app.add_subtask(1, "A3".to_string()).unwrap();
+        assert!(!app.tasks[0].collapsed);
+        app.move_selection_down();
+        assert_eq!(app.selected_path, vec![0]);
+
+        app.set_all_collapsed(true);
+        assert!(app.tasks[0].collapsed);
+        assert!(!app.tasks[1].collapsed);
+        assert_eq!((app.selected_index, app.selected_path.clone()), (0, vec![]));
+        app.set_all_collapsed(false);
+        assert!(!app.tasks[0].collapsed);
+    }
+
+    #[test]
+    fn test_page_and_jump_selection() {
+        let mut app = App::new();
+        app.select_last_row();
+        app.move_selection_page_down();
+        assert_eq!(app.selected_index, 0);
+
+        for i in 0..10 {
+            app.add_task(format!("Task {}", i)).unwrap();
+        }
+        app.selected_index = 4;
+        app.add_subtask(5, "Sub".to_string()).unwrap();
+        app.selected_index = 0;
+        app.task_list_height = 4;
+
+        app.move_selection_page_down();
+        assert_eq!((app.selected_index, app.selected_path.clone()), (4, vec![]));
+        app.move_selection_page_down();
+        assert_eq!((app.selected_index, app.selected_path.clone()), (7, vec![]));
+        app.move_selection_page_up();
+        assert_eq!((app.selected_index, app.selected_path.clone()), (4, vec![]));
+        app.move_selection_down();
+        assert_eq!(app.selected_row(), 5);
+        app.select_last_row();
+        assert_eq!(app.selected_row(), 10);
+        assert_eq!(app.selected_index, 9);
+        app.move_selection_page_down();
+        assert_eq!(app.selected_index, 9);
+        app.select_first_row();
+        assert_eq!(app.selected_row(), 0);
+    }
+
+    #[test]
+    fn test_priority_changes() {
+        let mut app = App::new();
+        assert!(!app.raise_selected_priority());
+        app.add_task("A".to_string()).unwrap();
+        assert!(!app.lower_selected_priority());
+        for _ in 0..5 {
+            app.raise_selected_priority();
+        }
+        assert_eq!(app.tasks[0].priority, Priority::Urgent);
+        assert!(app.lower_selected_priority());
+        assert_eq!(app.tasks[0].priority, Priority::High);
+    }
+
+    #[test]
+    fn test_priority_sort_is_stable() {
+        fn listed(app: &mut App) -> Vec<String> {
+            let selection = (app.selected_index, app.selected_path.clone());
+            let titles = (0..app.visible_row_count())
+                .map(|row| {
+                    app.select_row(row);
+                    app.tasks[app.selected_index].title.clone()
+                })
+                .collect();
+            (app.selected_index, app.selected_path) = selection;
+            titles
+        }
+
+        let mut app = App::new();
+        for title in ["A", "B", "C", "D"] {
+            app.add_task(title.to_string()).unwrap();
+        }
+        app.selected_index = 2;
+        app.raise_selected_priority();
+        app.selected_index = 3;
+        app.raise_selected_priority();
+
+        // Only the listing changes; the selection stays on D
+        app.toggle_priority_sort();
+        assert_eq!(listed(&mut app), vec!["C", "D", "A", "B"]);
+        let titles: Vec<&str> = app.tasks.iter().map(|t| t.title.as_str()).collect();
+        assert_eq!(titles, vec!["A", "B", "C", "D"]);
+        assert_eq!((app.selected_index, app.selected_row()), (3, 1));
+
+        // Moves stay within the same priority
+        assert!(app.move_task_up());
+        assert_eq!(listed(&mut app), vec!["D", "C", "A", "B"]);
+        assert_eq!(app.selected_row(), 0);
+        app.select_row(1);
+        assert!(!app.move_task_down());
+
+        app.select_row(2);
+        app.raise_selected_priority();
+        app.raise_selected_priority();
+        assert_eq!(listed(&mut app), vec!["A", "D", "C", "B"]);
+        assert_eq!(app.selected_row(), 0);
+
+        // Turning the sort off brings back the order the tasks were given
+        app.toggle_priority_sort();
+        assert_eq!(listed(&mut app), vec!["A", "B", "D", "C"]);
+    }
+
+    #[test]
+    fn test_tag_filter() {
+        let mut app = App::new();
+        app.add_task("A".to_string()).unwrap();
+        app.add_subtask(1, "A1 #work".to_string()).unwrap();
+        app.selected_path = vec![0];
+        app.add_subtask(2, "A1a".to_string()).unwrap();
+        app.add_task("B #home".to_string()).unwrap();
+        app.add_task("C".to_string()).unwrap();
+        app.selected_index = 2;
+        app.selected_path.clear();
+        assert!(app.set_selected_tags("#Work  urgent work").is_ok());
+        assert_eq!(app.get_selected_tags(), Some(&["Work".to_string(), "urgent".to_string()][..]));
+        assert_eq!(app.set_selected_tags("a#b"), Err("Invalid tag 'a#b'".to_string()));
+        assert!(app.set_selected_tags("##x").is_err());
+        assert!(app.set_tag_filter("#").is_err());
+
+        // B is hidden, so the selection moves on to C, which took its row
+        app.selected_index = 1;
+        app.set_tag_filter("#work").unwrap();
+        assert_eq!((app.selected_index, app.selected_row()), (2, 2));
+        assert_eq!(app.visible_row_count(), 3);
+
+        // A stays listed as the parent of A1, but A1a is hidden
+        app.select_first_row();
+        app.move_selection_down();
+        assert_eq!((app.selected_index, app.selected_path.clone()), (0, vec![0]));
+        app.move_selection_down();
+        assert_eq!((app.selected_index, app.selected_path.clone()), (2, vec![]));
+        app.move_selection_down();
+        assert_eq!(app.selected_index, 2);
+
+        app.set_tag_filter("home work").unwrap();
+        assert_eq!(app.visible_row_count(), 4);
+        app.set_tag_filter("").unwrap();
+        assert_eq!(app.visible_row_count(), 5);
+    }
+
+    #[test]
+    fn test_tag_filter_keeps_hidden_tasks_unselected() {
+        let mut app = App::new();
+        app.add_task("A #work".to_string()).unwrap();
+        app.add_task("B".to_string()).unwrap();
+        app.add_task("C #work".to_string()).unwrap();
+        app.add_task("D #work".to_string()).unwrap();
+        app.set_tag_filter("work").unwrap();
+
+        // Moves pass over B, which isn't listed
+        app.selected_index = 2;
+        assert!(app.move_task_up());
+        let titles: Vec<&str> = app.tasks.iter().map(|t| t.title.as_str()).collect();
+        assert_eq!(titles, ["C", "A", "B", "D"]);
+        assert_eq!(app.selected_index, 0);
+        assert!(!app.move_task_up());
+        app.selected_index = 1;
+        assert!(app.move_task_down());
+        let titles: Vec<&str> = app.tasks.iter().map(|t| t.title.as_str()).collect();
+        assert_eq!(titles, ["C", "B", "D", "A"]);
+        assert_eq!(app.selected_index, 3);
+
+        // Indenting D puts it under C, not under the hidden B
+        app.selected_index = 2;
+        assert!(app.indent_task());
+        assert_eq!(app.tasks[0].subtasks[0].title, "D");
+        assert_eq!((app.selected_index, app.selected_path.clone()), (0, vec![0]));
+
+        // Deleting C would leave B selected, so the selection moves on to A
+        app.selected_path.clear();
+        app.delete_selected_task();
+        assert_eq!(app.tasks[app.selected_index].title, "A");
+        app.delete_selected_task();
+        assert_eq!(app.tasks.len(), 1);
+        assert_eq!(app.visible_row_count(), 0);
+        app.delete_selected_task();
+        assert_eq!(app.tasks[0].title, "B");
+    }
+
+    #[test]
+    fn test_search() {
+        let mut app = App::new();
+        app.add_task("Write report".to_string()).unwrap();
+        app.add_subtask(1, "Outline".to_string()).unwrap();
+        app.selected_path = vec![0];
+        app.add_subtask(2, "Report figures".to_string()).unwrap();
+        app.add_task("Email #report".to_string()).unwrap();
+        app.add_task("Groceries".to_string()).unwrap();
+        app.set_all_collapsed(true);
+        app.selected_index = 2;
+
+        // Wraps around to the first match, and a tag counts as a match
+        assert!(app.set_search_query("REPORT"));
+        assert_eq!(app.search_matches(), vec![(0, vec![]), (0, vec![0, 0]), (1, vec![])]);
+        assert_eq!((app.selected_index, app.selected_path.clone()), (0, vec![]));
+
+        // The match inside the folded subtree is unfolded on the way to it
+        assert!(app.next_search_match());
+        assert_eq!((app.selected_index, app.selected_path.clone()), (0, vec![0, 0]));
+        assert!(!app.tasks[0].collapsed && !app.tasks[0].subtasks[0].collapsed);
+        assert_eq!(app.selected_row(), 2);
+        assert!(app.next_search_match());
+        assert_eq!(app.selected_index, 1);
+        assert!(app.previous_search_match());
+        assert_eq!(app.selected_path, vec![0, 0]);
+
+        // Typing more keeps the selection while it still matches
+        assert!(app.set_search_query("report fig"));
+        assert_eq!(app.selected_path, vec![0, 0]);
+        assert!(!app.set_search_query("missing"));
+        app.clear_search();
+        assert!(app.search_matches().is_empty());
+        assert!(!app.next_search_match());
+    }
+
+    #[test]
+    fn test_task_dates() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 16).unwrap();
+        let mut app = App::new();
+        app.add_task("Report".to_string()).unwrap();
+        app.add_subtask(1, "Draft".to_string()).unwrap();
+        app.add_task("Slides".to_string()).unwrap();
+
+        assert!(app.set_selected_date(DateField::Due, "2026-10-16"));
+        assert!(app.set_selected_date(DateField::Scheduled, " 2026-10-14 "));
+        assert!(!app.set_selected_date(DateField::Due, "soon"));
+        assert!(app.set_selected_date(DateField::Due, "tomorrow"));
+        assert_eq!(app.get_selected_date(DateField::Due), Local::now().date_naive().succ_opt());
+        assert!(app.set_selected_date(DateField::Due, "2026-10-16"));
+        assert_eq!(app.get_selected_date(DateField::Due), Some(today));
+        app.move_selection_down();
+        assert!(app.set_selected_date(DateField::Due, "2026-10-15"));
+        app.move_selection_down();
+        assert!(app.set_selected_date(DateField::Due, "2026-10-01"));
+        assert_eq!(app.due_counts(today), (1, 2));
+
+        app.toggle_task_completion();
+        assert_eq!(app.due_counts(today), (1, 1));
+        assert!(app.tasks[0].subtasks[0].is_overdue(today));
+        assert!(app.set_selected_date(DateField::Due, ""));
+        assert_eq!(app.tasks[1].due, None);
+    }
+
     #[test]
     fn test_validate_selected_index_empty() {
         let mut app = App::new();
@@ -877,7 +3259,7 @@ mod tests {
     #[test]
     fn test_validate_selected_index_out_of_bounds() {
         let mut app = App::new();
-        app.add_task("Task 1".to_string());
+        app.add_task("Task 1".to_string()).unwrap();
         app.selected_index = 5;
         app.validate_selected_index();
         assert_eq!(app.selected_index, 0);
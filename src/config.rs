@@ -0,0 +1,191 @@
+use crate::app::{PomodoroSettings, TimerMode, TransitionPolicy};
+use crate::hooks::HookConfig;
+use crate::notify::NotificationConfig;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+pub const USAGE: &str = "Usage: tsk [OPTIONS]
+
+Options:
+  --work <MINUTES>                Length of a work session
+  --short-break <MINUTES>         Length of a short break
+  --long-break <MINUTES>          Length of a long break
+  --long-break-interval <CYCLES>  Work sessions between long breaks
+  --daily-goal <POMODOROS>        Pomodoros to aim for each day
+  --mode <MODE>                   Timer mode: classic, overtime or flowtime
+  --flowtime-break-ratio <N>      In flowtime mode, break for 1/N of the time worked
+  --max-title-length <CHARS>      Longest task title accepted
+  --max-depth <LEVELS>            Levels of subtasks allowed (unlimited by default)
+  -h, --help                      Print this help";
+
+/// User configuration read from `~/.config/tui_pomo/config.json` and
+/// overridden by command-line flags. Unset values fall back to whatever
+/// was saved in `state.json`, or the built-in defaults.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub work_minutes: Option<u32>,
+    pub short_break_minutes: Option<u32>,
+    pub long_break_minutes: Option<u32>,
+    pub long_break_interval: Option<u32>,
+    pub daily_goal: Option<u32>,
+    pub mode: Option<TimerMode>,
+    pub flowtime_break_ratio: Option<u32>,
+    pub max_title_length: Option<u32>,
+    pub max_depth: Option<u32>,
+    pub transitions: TransitionPolicy,
+    pub notifications: NotificationConfig,
+    pub hooks: HookConfig,
+}
+
+pub fn config_dir() -> Result<PathBuf, Box<dyn std::error::Error>> {
+    Ok(dirs::config_dir()
+        .ok_or("Could not find config directory")?
+        .join("tui_pomo"))
+}
+
+impl Config {
+    pub fn load() -> Result<Self, Box<dyn std::error::Error>> {
+        let config_file = config_dir()?.join("config.json");
+        if !config_file.exists() {
+            return Ok(Self::default());
+        }
+
+        let json = std::fs::read_to_string(&config_file)
+            .map_err(|e| format!("Failed to read config file: {}. Error: {}", config_file.display(), e))?;
+        let config: Config = serde_json::from_str(&json)
+            .map_err(|e| format!("Failed to parse config file: {}. Error: {}", config_file.display(), e))?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Applies command-line flags on top of the values read from the config file.
+    /// Returns `Ok(false)` when help was requested.
+    pub fn apply_args<I>(&mut self, args: I) -> Result<bool, String>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let (flag, inline_value) = match arg.split_once('=') {
+                Some((flag, value)) => (flag.to_string(), Some(value.to_string())),
+                None => (arg.clone(), None),
+            };
+
+            if flag == "-h" || flag == "--help" {
+                return Ok(false);
+            }
+
+            // `None` for --mode, the one flag that doesn't take a number
+            let target = match flag.as_str() {
+                "--mode" => None,
+                "--work" => Some(&mut self.work_minutes),
+                "--short-break" => Some(&mut self.short_break_minutes),
+                "--long-break" => Some(&mut self.long_break_minutes),
+                "--long-break-interval" => Some(&mut self.long_break_interval),
+                "--daily-goal" => Some(&mut self.daily_goal),
+                "--flowtime-break-ratio" => Some(&mut self.flowtime_break_ratio),
+                "--max-title-length" => Some(&mut self.max_title_length),
+                "--max-depth" => Some(&mut self.max_depth),
+                _ => return Err(format!("Unknown argument '{}'", arg)),
+            };
+            let value = inline_value
+                .or_else(|| args.next())
+                .ok_or_else(|| format!("Missing value for '{}'", flag))?;
+            let invalid = || format!("Invalid value '{}' for '{}'", value, flag);
+
+            match target {
+                Some(target) => *target = Some(value.parse::<u32>().map_err(|_| invalid())?),
+                None => {
+                    self.mode = Some(match value.as_str() {
+                        "classic" => TimerMode::Classic,
+                        "overtime" => TimerMode::Overtime,
+                        "flowtime" => TimerMode::Flowtime,
+                        _ => return Err(invalid()),
+                    });
+                }
+            }
+        }
+        self.validate()?;
+        Ok(true)
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        let fields = [
+            ("work_minutes", self.work_minutes),
+            ("short_break_minutes", self.short_break_minutes),
+            ("long_break_minutes", self.long_break_minutes),
+            ("long_break_interval", self.long_break_interval),
+            ("daily_goal", self.daily_goal),
+            ("flowtime_break_ratio", self.flowtime_break_ratio),
+            ("max_title_length", self.max_title_length),
+            ("max_depth", self.max_depth),
+        ];
+        for (name, value) in fields {
+            if value == Some(0) {
+                return Err(format!("'{}' must be at least 1", name));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns `settings` with every value set in this config substituted in.
+    pub fn pomodoro_settings(&self, settings: PomodoroSettings) -> PomodoroSettings {
+        PomodoroSettings {
+            work_minutes: self.work_minutes.unwrap_or(settings.work_minutes),
+            short_break_minutes: self.short_break_minutes.unwrap_or(settings.short_break_minutes),
+            long_break_minutes: self.long_break_minutes.unwrap_or(settings.long_break_minutes),
+            long_break_interval: self.long_break_interval.unwrap_or(settings.long_break_interval),
+            mode: self.mode.unwrap_or(settings.mode),
+            flowtime_break_ratio: self.flowtime_break_ratio.unwrap_or(settings.flowtime_break_ratio),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_apply_args() {
+        let mut config = Config::default();
+        let result = config.apply_args(args(&["--work", "50", "--short-break=10", "--long-break-interval", "3"]));
+        assert_eq!(result, Ok(true));
+        assert_eq!(config.work_minutes, Some(50));
+        assert_eq!(config.short_break_minutes, Some(10));
+        assert_eq!(config.long_break_minutes, None);
+        assert_eq!(config.long_break_interval, Some(3));
+
+        assert_eq!(config.apply_args(args(&["--mode=flowtime"])), Ok(true));
+        assert_eq!(config.mode, Some(TimerMode::Flowtime));
+    }
+
+    #[test]
+    fn test_apply_args_invalid() {
+        let mut config = Config::default();
+        assert!(config.apply_args(args(&["--work"])).is_err());
+        assert!(config.apply_args(args(&["--work", "abc"])).is_err());
+        assert!(config.apply_args(args(&["--work", "0"])).is_err());
+        assert_eq!(config.apply_args(args(&["--bogus"])), Err("Unknown argument '--bogus'".to_string()));
+        assert!(config.apply_args(args(&["--mode", "sprint"])).is_err());
+        assert!(config.apply_args(args(&["--max-title-length", "0"])).is_err());
+    }
+
+    #[test]
+    fn test_pomodoro_settings_override() {
+        let config = Config {
+            work_minutes: Some(90),
+            long_break_minutes: Some(20),
+            ..Config::default()
+        };
+        let settings = config.pomodoro_settings(PomodoroSettings::default());
+        assert_eq!(settings.work_minutes, 90);
+        assert_eq!(settings.short_break_minutes, 5);
+        assert_eq!(settings.long_break_minutes, 20);
+        assert_eq!(settings.long_break_interval, 4);
+    }
+}
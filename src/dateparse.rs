@@ -0,0 +1,124 @@
+use chrono::{Datelike, Duration, Months, NaiveDate, Weekday};
+
+/// Resolves a date expression typed in the prompt relative to `today`.
+///
+/// Understands `today`/`eod`, `tomorrow`, `yesterday`, weekday names
+/// (`fri`, `friday`: the next one, today included), `next fri`,
+/// `next week` (next Monday), `next month` (the first of next month),
+/// `eow`/`eom` (end of this week or month), offsets such as `in 3d`,
+/// `+2w`, `in 1 month`, and dates written `2026-11-02` or `2026/11/02`.
+pub fn parse_date(input: &str, today: NaiveDate) -> Result<NaiveDate, String> {
+    let text = input.trim().to_lowercase();
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let unknown = || format!("Unrecognised date '{}'", input.trim());
+
+    match words.as_slice() {
+        [] => Err("Empty date".to_string()),
+        ["today" | "tod" | "eod" | "now"] => Ok(today),
+        ["tomorrow" | "tmr" | "tom"] => Ok(today + Duration::days(1)),
+        ["yesterday"] => Ok(today - Duration::days(1)),
+        ["eow"] | ["end", "of", "week"] => Ok(start_of_next_week(today) - Duration::days(1)),
+        ["eom"] | ["end", "of", "month"] => Ok(first_of_next_month(today).ok_or_else(unknown)? - Duration::days(1)),
+        ["next", "week"] => Ok(start_of_next_week(today)),
+        ["next", "month"] => first_of_next_month(today).ok_or_else(unknown),
+        ["next", day] => {
+            let weekday = parse_weekday(day).ok_or_else(unknown)?;
+            Ok(start_of_next_week(today) + Duration::days(weekday.num_days_from_monday() as i64))
+        }
+        ["in", amount, unit] => offset(today, amount, unit).ok_or_else(unknown),
+        ["in", amount] => split_offset(amount).and_then(|(n, unit)| offset(today, n, unit)).ok_or_else(unknown),
+        [single] => {
+            if let Some(weekday) = parse_weekday(single) {
+                let days_ahead = (weekday.num_days_from_monday() + 7 - today.weekday().num_days_from_monday()) % 7;
+                return Ok(today + Duration::days(days_ahead as i64));
+            }
+            let shorthand = single.strip_prefix('+').unwrap_or(single);
+            if let Some(date) = split_offset(shorthand).and_then(|(n, unit)| offset(today, n, unit)) {
+                return Ok(date);
+            }
+            NaiveDate::parse_from_str(single, "%Y-%m-%d")
+                .or_else(|_| NaiveDate::parse_from_str(single, "%Y/%m/%d"))
+                .map_err(|_| unknown())
+        }
+        _ => Err(unknown()),
+    }
+}
+
+fn parse_weekday(word: &str) -> Option<Weekday> {
+    match word {
+        "mon" | "monday" => Some(Weekday::Mon),
+        "tue" | "tues" | "tuesday" => Some(Weekday::Tue),
+        "wed" | "wednesday" => Some(Weekday::Wed),
+        "thu" | "thur" | "thurs" | "thursday" => Some(Weekday::Thu),
+        "fri" | "friday" => Some(Weekday::Fri),
+        "sat" | "saturday" => Some(Weekday::Sat),
+        "sun" | "sunday" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+/// Splits `3d` into `("3", "d")`.
+fn split_offset(word: &str) -> Option<(&str, &str)> {
+    let split = word.find(|c: char| !c.is_ascii_digit())?;
+    (split > 0).then(|| word.split_at(split))
+}
+
+fn offset(today: NaiveDate, amount: &str, unit: &str) -> Option<NaiveDate> {
+    let amount: u32 = amount.parse().ok()?;
+    match unit {
+        "d" | "day" | "days" => today.checked_add_signed(Duration::days(amount as i64)),
+        "w" | "wk" | "week" | "weeks" => today.checked_add_signed(Duration::weeks(amount as i64)),
+        "m" | "mo" | "month" | "months" => today.checked_add_months(Months::new(amount)),
+        "y" | "year" | "years" => today.checked_add_months(Months::new(amount.checked_mul(12)?)),
+        _ => None,
+    }
+}
+
+fn start_of_next_week(today: NaiveDate) -> NaiveDate {
+    today + Duration::days(7 - today.weekday().num_days_from_monday() as i64)
+}
+
+fn first_of_next_month(today: NaiveDate) -> Option<NaiveDate> {
+    today.with_day(1)?.checked_add_months(Months::new(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_relative_dates() {
+        // A Friday
+        let today = date(2026, 10, 16);
+        assert_eq!(parse_date("today", today), Ok(today));
+        assert_eq!(parse_date(" EOD ", today), Ok(today));
+        assert_eq!(parse_date("tomorrow", today), Ok(date(2026, 10, 17)));
+        assert_eq!(parse_date("fri", today), Ok(today));
+        assert_eq!(parse_date("Monday", today), Ok(date(2026, 10, 19)));
+        assert_eq!(parse_date("next fri", today), Ok(date(2026, 10, 23)));
+        assert_eq!(parse_date("next week", today), Ok(date(2026, 10, 19)));
+        assert_eq!(parse_date("next month", today), Ok(date(2026, 11, 1)));
+        assert_eq!(parse_date("eow", today), Ok(date(2026, 10, 18)));
+        assert_eq!(parse_date("eom", today), Ok(date(2026, 10, 31)));
+    }
+
+    #[test]
+    fn test_offsets_and_absolute_dates() {
+        let today = date(2026, 1, 31);
+        assert_eq!(parse_date("in 3d", today), Ok(date(2026, 2, 3)));
+        assert_eq!(parse_date("in 2 weeks", today), Ok(date(2026, 2, 14)));
+        assert_eq!(parse_date("+1m", today), Ok(date(2026, 2, 28)));
+        assert_eq!(parse_date("10d", today), Ok(date(2026, 2, 10)));
+        assert_eq!(parse_date("2026-11-02", today), Ok(date(2026, 11, 2)));
+        assert_eq!(parse_date("2026/11/02", today), Ok(date(2026, 11, 2)));
+
+        assert!(parse_date("", today).is_err());
+        assert!(parse_date("in 3x", today).is_err());
+        assert!(parse_date("2026-02-30", today).is_err());
+        assert_eq!(parse_date("someday", today), Err("Unrecognised date 'someday'".to_string()));
+    }
+}
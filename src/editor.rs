@@ -0,0 +1,186 @@
+use unicode_width::UnicodeWidthStr;
+
+/// Single-line text editor behind the input prompt. The cursor is a byte
+/// offset into the text and always sits on a char boundary.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LineEditor {
+    text: String,
+    cursor: usize,
+}
+
+impl LineEditor {
+    /// Creates an editor holding `text` with the cursor at its end.
+    pub fn new(text: String) -> Self {
+        Self {
+            cursor: text.len(),
+            text,
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn clear(&mut self) {
+        self.text.clear();
+        self.cursor = 0;
+    }
+
+    pub fn insert(&mut self, c: char) {
+        self.text.insert(self.cursor, c);
+        self.cursor += c.len_utf8();
+    }
+
+    /// Inserts pasted text. Line breaks and other control characters become
+    /// spaces, since the prompt holds a single line.
+    pub fn insert_str(&mut self, text: &str) {
+        let cleaned: String = text
+            .trim_end_matches(['\r', '\n'])
+            .chars()
+            .map(|c| if c.is_control() { ' ' } else { c })
+            .collect();
+        self.text.insert_str(self.cursor, &cleaned);
+        self.cursor += cleaned.len();
+    }
+
+    /// Deletes the character before the cursor.
+    pub fn delete_before(&mut self) {
+        let start = self.prev_char_boundary();
+        self.text.replace_range(start..self.cursor, "");
+        self.cursor = start;
+    }
+
+    /// Deletes the character under the cursor.
+    pub fn delete_after(&mut self) {
+        let end = self.next_char_boundary();
+        self.text.replace_range(self.cursor..end, "");
+    }
+
+    /// Deletes back to the start of the word before the cursor (Ctrl-W).
+    pub fn delete_word_before(&mut self) {
+        let start = self.prev_word_boundary();
+        self.text.replace_range(start..self.cursor, "");
+        self.cursor = start;
+    }
+
+    /// Deletes everything before the cursor (Ctrl-U).
+    pub fn delete_to_start(&mut self) {
+        self.text.replace_range(..self.cursor, "");
+        self.cursor = 0;
+    }
+
+    /// Deletes everything from the cursor on (Ctrl-K).
+    pub fn delete_to_end(&mut self) {
+        self.text.truncate(self.cursor);
+    }
+
+    pub fn move_left(&mut self) {
+        self.cursor = self.prev_char_boundary();
+    }
+
+    pub fn move_right(&mut self) {
+        self.cursor = self.next_char_boundary();
+    }
+
+    pub fn move_home(&mut self) {
+        self.cursor = 0;
+    }
+
+    pub fn move_end(&mut self) {
+        self.cursor = self.text.len();
+    }
+
+    /// Moves to the start of the current or previous word.
+    pub fn move_word_left(&mut self) {
+        self.cursor = self.prev_word_boundary();
+    }
+
+    /// Moves past the end of the current or next word.
+    pub fn move_word_right(&mut self) {
+        let rest = &self.text[self.cursor..];
+        let word_start = rest.find(|c: char| !c.is_whitespace()).unwrap_or(rest.len());
+        let word_len = rest[word_start..].find(char::is_whitespace).unwrap_or(rest.len() - word_start);
+        self.cursor += word_start + word_len;
+    }
+
+    /// Terminal columns taken up by the text before the cursor.
+    pub fn cursor_width(&self) -> usize {
+        self.text[..self.cursor].width()
+    }
+
+    fn prev_char_boundary(&self) -> usize {
+        self.text[..self.cursor]
+            .chars()
+            .next_back()
+            .map_or(self.cursor, |c| self.cursor - c.len_utf8())
+    }
+
+    fn next_char_boundary(&self) -> usize {
+        self.text[self.cursor..]
+            .chars()
+            .next()
+            .map_or(self.cursor, |c| self.cursor + c.len_utf8())
+    }
+
+    fn prev_word_boundary(&self) -> usize {
+        let before = self.text[..self.cursor].trim_end();
+        before
+            .char_indices()
+            .rev()
+            .find(|(_, c)| c.is_whitespace())
+            .map_or(0, |(idx, c)| idx + c.len_utf8())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_char_editing() {
+        let mut editor = LineEditor::new("café".to_string());
+        assert_eq!(editor.cursor, 5);
+        editor.move_left();
+        editor.delete_before();
+        assert_eq!(editor.text(), "caé");
+        editor.insert('f');
+        assert_eq!(editor.text(), "café");
+        editor.move_home();
+        editor.delete_after();
+        editor.insert('C');
+        assert_eq!(editor.text(), "Café");
+        editor.move_end();
+        editor.move_right();
+        editor.delete_after();
+        assert_eq!(editor.cursor, editor.text().len());
+    }
+
+    #[test]
+    fn test_word_editing() {
+        let mut editor = LineEditor::new("write the  report".to_string());
+        editor.move_word_left();
+        assert_eq!(editor.cursor, 11);
+        editor.move_word_left();
+        assert_eq!(editor.cursor, 6);
+        editor.move_word_right();
+        assert_eq!(editor.cursor, 9);
+
+        editor.delete_word_before();
+        assert_eq!(editor.text(), "write   report");
+        editor.delete_to_end();
+        assert_eq!(editor.text(), "write ");
+        editor.move_left();
+        editor.delete_to_start();
+        assert_eq!(editor.text(), " ");
+        assert_eq!(editor.cursor, 0);
+    }
+
+    #[test]
+    fn test_paste_and_width() {
+        let mut editor = LineEditor::default();
+        editor.insert_str("日本\tgo\n");
+        assert_eq!(editor.text(), "日本 go");
+        editor.move_word_left();
+        assert_eq!(editor.cursor_width(), 5);
+    }
+}
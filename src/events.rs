@@ -0,0 +1,54 @@
+use crate::app::PomodoroState;
+use crate::history::SessionOutcome;
+use chrono::{DateTime, Local};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AppEventKind {
+    WorkStart,
+    WorkEnd,
+    BreakStart,
+    BreakEnd,
+    TaskCompleted,
+    TaskAdded,
+}
+
+impl AppEventKind {
+    pub fn phase_start(phase: PomodoroState) -> Self {
+        match phase {
+            PomodoroState::Work => Self::WorkStart,
+            PomodoroState::ShortBreak | PomodoroState::LongBreak => Self::BreakStart,
+        }
+    }
+
+    pub fn phase_end(phase: PomodoroState) -> Self {
+        match phase {
+            PomodoroState::Work => Self::WorkEnd,
+            PomodoroState::ShortBreak | PomodoroState::LongBreak => Self::BreakEnd,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::WorkStart => "work_start",
+            Self::WorkEnd => "work_end",
+            Self::BreakStart => "break_start",
+            Self::BreakEnd => "break_end",
+            Self::TaskCompleted => "task_completed",
+            Self::TaskAdded => "task_added",
+        }
+    }
+}
+
+/// Something that happened in the app, queued for hooks to act on.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AppEvent {
+    pub event: AppEventKind,
+    pub timestamp: DateTime<Local>,
+    pub phase: Option<PomodoroState>,
+    pub outcome: Option<SessionOutcome>,
+    pub cycles: usize,
+    pub task_id: Option<usize>,
+    pub task_title: Option<String>,
+}
@@ -0,0 +1,163 @@
+use crate::app::{PomodoroState, TimerMode};
+use crate::config::config_dir;
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SessionOutcome {
+    Completed,
+    Reset,
+    /// Cut short to move on to the next phase; no cycle was credited.
+    Skipped,
+}
+
+/// One Pomodoro phase, written as a line of `~/.config/tui_pomo/history.jsonl`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionRecord {
+    pub phase: PomodoroState,
+    pub started_at: DateTime<Local>,
+    pub ended_at: DateTime<Local>,
+    pub outcome: SessionOutcome,
+    pub task_id: Option<usize>,
+    pub task_title: Option<String>,
+    #[serde(default)]
+    pub mode: TimerMode,
+    /// Planned length in seconds; `None` for open-ended flowtime work.
+    #[serde(default)]
+    pub planned_seconds: Option<i64>,
+    /// Seconds the timer actually ran, leaving out pauses; `None` in
+    /// records written before this was tracked.
+    #[serde(default)]
+    pub active_seconds: Option<i64>,
+}
+
+pub fn load_history() -> Result<Vec<SessionRecord>, Box<dyn std::error::Error>> {
+    let history_file = config_dir()?.join("history.jsonl");
+    if !history_file.exists() {
+        return Ok(Vec::new());
+    }
+
+    let contents = std::fs::read_to_string(&history_file)
+        .map_err(|e| format!("Failed to read history file: {}. Error: {}", history_file.display(), e))?;
+    Ok(parse_history(&contents))
+}
+
+fn parse_history(contents: &str) -> Vec<SessionRecord> {
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .filter_map(|(idx, line)| match serde_json::from_str(line) {
+            Ok(record) => Some(record),
+            Err(e) => {
+                eprintln!("Warning: Skipping invalid history entry on line {}: {}", idx + 1, e);
+                None
+            }
+        })
+        .collect()
+}
+
+/// Appends `records` to the history file, one JSON object per line.
+pub fn append_history(records: &[SessionRecord]) -> Result<(), Box<dyn std::error::Error>> {
+    if records.is_empty() {
+        return Ok(());
+    }
+    let config_dir = config_dir()?;
+    std::fs::create_dir_all(&config_dir)?;
+
+    let mut output = String::new();
+    for record in records {
+        output.push_str(&serde_json::to_string(record)?);
+        output.push('\n');
+    }
+
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(config_dir.join("history.jsonl"))?;
+    file.write_all(output.as_bytes())?;
+    Ok(())
+}
+
+/// Replaces the latest line holding each old record with its new version.
+pub fn amend_history(changes: &[(SessionRecord, SessionRecord)]) -> Result<(), Box<dyn std::error::Error>> {
+    let history_file = config_dir()?.join("history.jsonl");
+    if changes.is_empty() || !history_file.exists() {
+        return Ok(());
+    }
+
+    let contents = std::fs::read_to_string(&history_file)
+        .map_err(|e| format!("Failed to read history file: {}. Error: {}", history_file.display(), e))?;
+    let amended = amend_lines(&contents, changes)?;
+    std::fs::write(&history_file, amended)?;
+    Ok(())
+}
+
+fn amend_lines(contents: &str, changes: &[(SessionRecord, SessionRecord)]) -> Result<String, serde_json::Error> {
+    let mut lines: Vec<String> = contents.lines().map(str::to_string).collect();
+    for (old, new) in changes {
+        let found = lines
+            .iter()
+            .rposition(|line| serde_json::from_str::<SessionRecord>(line).is_ok_and(|record| record == *old));
+        if let Some(idx) = found {
+            lines[idx] = serde_json::to_string(new)?;
+        }
+    }
+
+    let mut output = lines.join("\n");
+    if !output.is_empty() {
+        output.push('\n');
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_parse_history_skips_invalid_lines() {
+        let now = Local::now();
+        let record = SessionRecord {
+            phase: PomodoroState::Work,
+            started_at: now - Duration::minutes(25),
+            ended_at: now,
+            outcome: SessionOutcome::Completed,
+            task_id: Some(3),
+            task_title: Some("Write report".to_string()),
+            mode: TimerMode::Overtime,
+            planned_seconds: Some(20 * 60),
+            active_seconds: Some(20 * 60),
+        };
+        let contents = format!("{}\nnot json\n\n{}\n", serde_json::to_string(&record).unwrap(), serde_json::to_string(&record).unwrap());
+        let parsed = parse_history(&contents);
+        assert_eq!(parsed, vec![record.clone(), record]);
+    }
+
+    #[test]
+    fn test_amend_lines_replaces_latest_match() {
+        let now = Local::now();
+        let record = SessionRecord {
+            phase: PomodoroState::Work,
+            started_at: now - Duration::minutes(25),
+            ended_at: now,
+            outcome: SessionOutcome::Completed,
+            task_id: None,
+            task_title: None,
+            mode: TimerMode::Classic,
+            planned_seconds: Some(25 * 60),
+            active_seconds: Some(25 * 60),
+        };
+        let reset = SessionRecord {
+            outcome: SessionOutcome::Reset,
+            ..record.clone()
+        };
+        let line = serde_json::to_string(&record).unwrap();
+        let contents = format!("{}\nnot json\n{}\n", line, line);
+        let amended = amend_lines(&contents, &[(record.clone(), reset.clone())]).unwrap();
+        assert_eq!(parse_history(&amended), vec![record, reset]);
+        assert!(amended.contains("not json"));
+    }
+}
@@ -0,0 +1,118 @@
+use crate::events::{AppEvent, AppEventKind};
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Shell commands run on app events. Each command gets the event details as
+/// `TSK_*` environment variables and as a JSON object on stdin.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HookConfig {
+    pub work_start: Option<String>,
+    pub work_end: Option<String>,
+    pub break_start: Option<String>,
+    pub break_end: Option<String>,
+    pub task_completed: Option<String>,
+    pub task_added: Option<String>,
+}
+
+impl HookConfig {
+    fn command_for(&self, kind: AppEventKind) -> Option<&str> {
+        match kind {
+            AppEventKind::WorkStart => self.work_start.as_deref(),
+            AppEventKind::WorkEnd => self.work_end.as_deref(),
+            AppEventKind::BreakStart => self.break_start.as_deref(),
+            AppEventKind::BreakEnd => self.break_end.as_deref(),
+            AppEventKind::TaskCompleted => self.task_completed.as_deref(),
+            AppEventKind::TaskAdded => self.task_added.as_deref(),
+        }
+    }
+
+    /// Runs the hook for `event` in the background, if one is configured.
+    /// Failures are ignored so a broken script never interrupts the app.
+    pub fn run(&self, event: &AppEvent) {
+        let Some(command) = self.command_for(event.event) else {
+            return;
+        };
+        let Ok(payload) = serde_json::to_string(event) else {
+            return;
+        };
+
+        let child = Command::new("sh")
+            .arg("-c")
+            .arg(command)
+            .envs(hook_env(event))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn();
+        if let Ok(mut child) = child {
+            if let Some(mut stdin) = child.stdin.take() {
+                let _ = stdin.write_all(payload.as_bytes());
+            }
+            // Reap the child without blocking the UI
+            std::thread::spawn(move || {
+                let _ = child.wait();
+            });
+        }
+    }
+}
+
+fn hook_env(event: &AppEvent) -> Vec<(&'static str, String)> {
+    let mut env = vec![
+        ("TSK_EVENT", event.event.name().to_string()),
+        ("TSK_TIMESTAMP", event.timestamp.to_rfc3339()),
+        ("TSK_CYCLES", event.cycles.to_string()),
+    ];
+    if let Some(phase) = event.phase {
+        env.push(("TSK_PHASE", format!("{:?}", phase)));
+    }
+    if let Some(outcome) = event.outcome {
+        env.push(("TSK_OUTCOME", format!("{:?}", outcome)));
+    }
+    if let Some(id) = event.task_id {
+        env.push(("TSK_TASK_ID", id.to_string()));
+    }
+    if let Some(title) = &event.task_title {
+        env.push(("TSK_TASK_TITLE", title.clone()));
+    }
+    env
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::PomodoroState;
+    use crate::history::SessionOutcome;
+    use chrono::Local;
+
+    #[test]
+    fn test_hook_env() {
+        let event = AppEvent {
+            event: AppEventKind::WorkEnd,
+            timestamp: Local::now(),
+            phase: Some(PomodoroState::Work),
+            outcome: Some(SessionOutcome::Completed),
+            cycles: 3,
+            task_id: Some(7),
+            task_title: Some("Report".to_string()),
+        };
+        let env = hook_env(&event);
+        assert!(env.contains(&("TSK_EVENT", "work_end".to_string())));
+        assert!(env.contains(&("TSK_PHASE", "Work".to_string())));
+        assert!(env.contains(&("TSK_OUTCOME", "Completed".to_string())));
+        assert!(env.contains(&("TSK_CYCLES", "3".to_string())));
+        assert!(env.contains(&("TSK_TASK_ID", "7".to_string())));
+        assert!(env.contains(&("TSK_TASK_TITLE", "Report".to_string())));
+    }
+
+    #[test]
+    fn test_command_for() {
+        let hooks = HookConfig {
+            break_start: Some("playerctl pause".to_string()),
+            ..HookConfig::default()
+        };
+        assert_eq!(hooks.command_for(AppEventKind::BreakStart), Some("playerctl pause"));
+        assert_eq!(hooks.command_for(AppEventKind::WorkStart), None);
+    }
+}
@@ -1,33 +1,65 @@
-use crate::app::{App, InputMode};
+use crate::app::{App, DateField, InputMode};
+use crate::quickadd::escape_tokens;
 use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 pub fn handle_input(app: &mut App) -> Result<bool, Box<dyn std::error::Error>> {
     if event::poll(std::time::Duration::from_millis(50))? {
-        if let Event::Key(key) = event::read()? {
-            if key.kind != KeyEventKind::Press {
-                return Ok(false);
-            }
-
-            match app.input_mode {
-                InputMode::Normal => {
-                    return handle_normal_input(app, key);
-                }
-                InputMode::AddingTask | InputMode::AddingSubtask(_) => {
-                    return handle_input_mode(app, key);
-                }
-                InputMode::Menu => {
-                    return handle_menu_input(app, key);
+        match event::read()? {
+            Event::Key(key) => {
+                if key.kind != KeyEventKind::Press {
+                    return Ok(false);
                 }
-                InputMode::ConfirmingDelete | InputMode::ConfirmingClear => {
-                    return handle_confirmation_input(app, key);
+
+                match app.input_mode {
+                    InputMode::Normal => {
+                        return handle_normal_input(app, key);
+                    }
+                    InputMode::AddingTask
+                    | InputMode::AddingSubtask(_)
+                    | InputMode::EditingEstimate
+                    | InputMode::EditingTask
+                    | InputMode::EditingDate(_)
+                    | InputMode::EditingTags
+                    | InputMode::FilteringTags
+                    | InputMode::Searching => {
+                        return handle_input_mode(app, key);
+                    }
+                    InputMode::Menu => {
+                        return handle_menu_input(app, key);
+                    }
+                    InputMode::Stats => {
+                        return handle_stats_input(app, key);
+                    }
+                    InputMode::ConfirmingDelete | InputMode::ConfirmingClear => {
+                        return handle_confirmation_input(app, key);
+                    }
                 }
             }
+            Event::Paste(text) if is_editing(&app.input_mode) => {
+                app.input_buffer.insert_str(&text);
+                update_search(app);
+            }
+            _ => {}
         }
     }
     Ok(false)
 }
 
+fn is_editing(mode: &InputMode) -> bool {
+    matches!(
+        mode,
+        InputMode::AddingTask
+            | InputMode::AddingSubtask(_)
+            | InputMode::EditingEstimate
+            | InputMode::EditingTask
+            | InputMode::EditingDate(_)
+            | InputMode::EditingTags
+            | InputMode::FilteringTags
+            | InputMode::Searching
+    )
+}
+
 fn handle_normal_input(app: &mut App, key: KeyEvent) -> Result<bool, Box<dyn std::error::Error>> {
     match key.code {
         KeyCode::Char('q') => {
@@ -36,13 +68,17 @@ fn handle_normal_input(app: &mut App, key: KeyEvent) -> Result<bool, Box<dyn std
             return Ok(true);
         }
         KeyCode::Char('a') => {
-            app.input_mode = InputMode::AddingTask;
-            app.input_buffer.clear();
+            app.start_input(InputMode::AddingTask, String::new());
         }
         KeyCode::Char('s') => {
             if let Some(parent_id) = app.get_selected_parent_id() {
-                app.input_mode = InputMode::AddingSubtask(parent_id);
-                app.input_buffer.clear();
+                app.start_input(InputMode::AddingSubtask(parent_id), String::new());
+            }
+        }
+        KeyCode::Char('i') => {
+            // Escaped so words that look like tokens stay in the title
+            if let Some(title) = app.get_selected_title().map(escape_tokens) {
+                app.start_input(InputMode::EditingTask, title);
             }
         }
         KeyCode::Char('x') => {
@@ -50,22 +86,130 @@ fn handle_normal_input(app: &mut App, key: KeyEvent) -> Result<bool, Box<dyn std
             let _ = app.save_state();
             let _ = app.save_tasks_to_txt();
         }
+        KeyCode::Char('e') => {
+            if app.get_selected_parent_id().is_some() {
+                let estimate = app.get_selected_estimate().map(|n| n.to_string()).unwrap_or_default();
+                app.start_input(InputMode::EditingEstimate, estimate);
+            }
+        }
+        KeyCode::Char('D') | KeyCode::Char('S') => {
+            let field = if key.code == KeyCode::Char('D') { DateField::Due } else { DateField::Scheduled };
+            if app.get_selected_parent_id().is_some() {
+                let date = app.get_selected_date(field).map(|d| d.format("%Y-%m-%d").to_string()).unwrap_or_default();
+                app.start_input(InputMode::EditingDate(field), date);
+            }
+        }
+        KeyCode::Char('#') => {
+            if let Some(tags) = app.get_selected_tags() {
+                let tags = tags.join(" ");
+                app.start_input(InputMode::EditingTags, tags);
+            }
+        }
+        KeyCode::Char('T') => {
+            let filter = app.tag_filter.join(" ");
+            app.start_input(InputMode::FilteringTags, filter);
+        }
+        KeyCode::Char('/') => {
+            let query = app.search_query.clone();
+            app.start_input(InputMode::Searching, query);
+        }
+        KeyCode::Char('n') => {
+            app.next_search_match();
+        }
+        KeyCode::Char('N') => {
+            app.previous_search_match();
+        }
+        KeyCode::Char('f') => {
+            app.toggle_focus();
+            let _ = app.save_state();
+        }
         KeyCode::Up | KeyCode::Char('k') => {
             app.move_selection_up();
         }
         KeyCode::Down | KeyCode::Char('j') => {
             app.move_selection_down();
         }
+        KeyCode::PageUp => {
+            app.move_selection_page_up();
+        }
+        KeyCode::PageDown => {
+            app.move_selection_page_down();
+        }
+        KeyCode::Home | KeyCode::Char('g') => {
+            app.select_first_row();
+        }
+        KeyCode::End | KeyCode::Char('G') => {
+            app.select_last_row();
+        }
+        KeyCode::Char(')') | KeyCode::Char('(') => {
+            let changed = if key.code == KeyCode::Char(')') {
+                app.raise_selected_priority()
+            } else {
+                app.lower_selected_priority()
+            };
+            if changed {
+                let _ = app.save_state();
+                let _ = app.save_tasks_to_txt();
+            }
+        }
+        KeyCode::Char('o') => {
+            app.toggle_priority_sort();
+            let _ = app.save_state();
+            let _ = app.save_tasks_to_txt();
+        }
+        KeyCode::Char('z') => {
+            if app.toggle_collapsed() {
+                let _ = app.save_state();
+            }
+        }
+        KeyCode::Char('M') => {
+            app.set_all_collapsed(true);
+            let _ = app.save_state();
+        }
+        KeyCode::Char('R') => {
+            app.set_all_collapsed(false);
+            let _ = app.save_state();
+        }
+        KeyCode::Char('K') | KeyCode::Char('J') | KeyCode::Char('>') | KeyCode::Char('<') => {
+            let moved = match key.code {
+                KeyCode::Char('K') => app.move_task_up(),
+                KeyCode::Char('J') => app.move_task_down(),
+                KeyCode::Char('>') => app.indent_task(),
+                _ => app.outdent_task(),
+            };
+            if moved {
+                let _ = app.save_state();
+                let _ = app.save_tasks_to_txt();
+            }
+        }
         KeyCode::Char('p') => {
-            app.pomodoro.toggle();
+            app.toggle_pomodoro();
         }
         KeyCode::Char('r') => {
-            app.pomodoro.reset();
+            app.reset_pomodoro();
+        }
+        KeyCode::Char('d') => {
+            app.end_pomodoro_phase();
+        }
+        KeyCode::Char(']') => {
+            app.skip_pomodoro_phase();
+        }
+        KeyCode::Char('[') => {
+            app.previous_pomodoro_phase();
+        }
+        KeyCode::Char('+') | KeyCode::Char('=') => {
+            app.adjust_pomodoro(1);
+        }
+        KeyCode::Char('-') => {
+            app.adjust_pomodoro(-1);
         }
         KeyCode::Char('t') => {
             app.cycle_theme();
             let _ = app.save_state();
         }
+        KeyCode::Char('v') => {
+            app.input_mode = InputMode::Stats;
+        }
         KeyCode::Char('w') => {
             let _ = app.save_state();
             let _ = app.save_tasks_to_txt();
@@ -100,46 +244,127 @@ fn handle_normal_input(app: &mut App, key: KeyEvent) -> Result<bool, Box<dyn std
 fn handle_input_mode(app: &mut App, key: KeyEvent) -> Result<bool, Box<dyn std::error::Error>> {
     match key.code {
         KeyCode::Enter => {
-            match &app.input_mode {
+            let input = app.input_buffer.text().to_string();
+            let added = match &app.input_mode {
                 InputMode::AddingTask => {
-                    if app.add_task(app.input_buffer.clone()) {
-                        app.input_mode = InputMode::Normal;
-                        app.input_buffer.clear();
-                        let _ = app.save_state();
-                        let _ = app.save_tasks_to_txt();
-                    }
+                    let result = app.add_task(input);
+                    report_error(app, result)
                 }
                 InputMode::AddingSubtask(parent_id) => {
-                    if app.add_subtask(*parent_id, app.input_buffer.clone()) {
-                        app.input_mode = InputMode::Normal;
-                        app.input_buffer.clear();
-                        let _ = app.save_state();
-                        let _ = app.save_tasks_to_txt();
-                    }
+                    let result = app.add_subtask(*parent_id, input);
+                    report_error(app, result)
                 }
-                _ => {}
+                InputMode::EditingEstimate => {
+                    let result = app.set_selected_estimate(&input);
+                    report_error(app, result)
+                }
+                InputMode::EditingTask => {
+                    let result = app.rename_selected_task(input);
+                    report_error(app, result)
+                }
+                InputMode::EditingDate(field) => app.set_selected_date(*field, &input),
+                InputMode::EditingTags => {
+                    let result = app.set_selected_tags(&input);
+                    report_error(app, result)
+                }
+                InputMode::FilteringTags => {
+                    let result = app.set_tag_filter(&input);
+                    report_error(app, result)
+                }
+                // Keeps the query so n/N can move between matches
+                InputMode::Searching => true,
+                _ => false,
+            };
+            if added {
+                app.cancel_input();
+                let _ = app.save_state();
+                let _ = app.save_tasks_to_txt();
             }
         }
         KeyCode::Esc => {
-            app.input_mode = InputMode::Normal;
-            app.input_buffer.clear();
+            if app.input_mode == InputMode::Searching {
+                app.clear_search();
+            }
+            app.cancel_input();
         }
-        KeyCode::Char(c) => {
-            if key.modifiers.contains(KeyModifiers::CONTROL) && c == 'c' {
-                app.input_mode = InputMode::Normal;
-                app.input_buffer.clear();
-            } else {
-                app.input_buffer.push(c);
+        KeyCode::Char(c) if key.modifiers.contains(KeyModifiers::CONTROL) => match c {
+            'c' => {
+                if app.input_mode == InputMode::Searching {
+                    app.clear_search();
+                }
+                app.cancel_input();
             }
+            'a' => app.input_buffer.move_home(),
+            'e' => app.input_buffer.move_end(),
+            'b' => app.input_buffer.move_left(),
+            'f' => app.input_buffer.move_right(),
+            'd' => app.input_buffer.delete_after(),
+            'h' => app.input_buffer.delete_before(),
+            'w' => app.input_buffer.delete_word_before(),
+            'u' => app.input_buffer.delete_to_start(),
+            'k' => app.input_buffer.delete_to_end(),
+            _ => {}
+        },
+        KeyCode::Char(c) if key.modifiers.contains(KeyModifiers::ALT) => match c {
+            'b' => app.input_buffer.move_word_left(),
+            'f' => app.input_buffer.move_word_right(),
+            _ => {}
+        },
+        KeyCode::Char(c) => {
+            app.input_buffer.insert(c);
+        }
+        KeyCode::Backspace if key.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) => {
+            app.input_buffer.delete_word_before();
         }
         KeyCode::Backspace => {
-            app.input_buffer.pop();
+            app.input_buffer.delete_before();
+        }
+        KeyCode::Delete => {
+            app.input_buffer.delete_after();
+        }
+        KeyCode::Left if key.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) => {
+            app.input_buffer.move_word_left();
+        }
+        KeyCode::Right if key.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) => {
+            app.input_buffer.move_word_right();
+        }
+        KeyCode::Left => {
+            app.input_buffer.move_left();
+        }
+        KeyCode::Right => {
+            app.input_buffer.move_right();
+        }
+        KeyCode::Home => {
+            app.input_buffer.move_home();
+        }
+        KeyCode::End => {
+            app.input_buffer.move_end();
         }
         _ => {}
     }
+    update_search(app);
     Ok(false)
 }
 
+/// Searches again for what's been typed so far while in search mode.
+fn update_search(app: &mut App) {
+    if app.input_mode == InputMode::Searching {
+        let query = app.input_buffer.text().to_string();
+        app.set_search_query(&query);
+    }
+}
+
+/// Shows why the input was rejected, leaving the prompt open to fix it.
+fn report_error(app: &mut App, result: Result<(), String>) -> bool {
+    match result {
+        Ok(()) => true,
+        Err(message) => {
+            app.show_notification(message, Duration::from_secs(3));
+            false
+        }
+    }
+}
+
 fn handle_menu_input(app: &mut App, key: KeyEvent) -> Result<bool, Box<dyn std::error::Error>> {
     match key.code {
         KeyCode::Esc | KeyCode::Char('q') => {
@@ -161,7 +386,7 @@ fn handle_menu_input(app: &mut App, key: KeyEvent) -> Result<bool, Box<dyn std::
                     app.input_mode = InputMode::Normal;
                 }
                 "Reset Pomodoro" => {
-                    app.pomodoro.reset();
+                    app.reset_pomodoro();
                     app.input_mode = InputMode::Normal;
                 }
                 "Save Tasks" => {
@@ -178,6 +403,9 @@ fn handle_menu_input(app: &mut App, key: KeyEvent) -> Result<bool, Box<dyn std::
                     app.input_mode = InputMode::Normal;
                     let _ = app.save_state();
                 }
+                "Statistics" => {
+                    app.input_mode = InputMode::Stats;
+                }
                 "Quit" => {
                     app.save_state()?;
                     let _ = app.save_tasks_to_txt();
@@ -191,6 +419,16 @@ fn handle_menu_input(app: &mut App, key: KeyEvent) -> Result<bool, Box<dyn std::
     Ok(false)
 }
 
+fn handle_stats_input(app: &mut App, key: KeyEvent) -> Result<bool, Box<dyn std::error::Error>> {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('v') => {
+            app.input_mode = InputMode::Normal;
+        }
+        _ => {}
+    }
+    Ok(false)
+}
+
 fn handle_confirmation_input(app: &mut App, key: KeyEvent) -> Result<bool, Box<dyn std::error::Error>> {
     match key.code {
         KeyCode::Char('y') | KeyCode::Char('Y') => {
@@ -1,11 +1,21 @@
 mod app;
+mod config;
+mod dateparse;
+mod editor;
+mod events;
+mod history;
+mod hooks;
 mod input;
+mod notify;
+mod quickadd;
+mod stats;
 mod theme;
 mod ui;
 
 use app::App;
+use config::Config;
 use crossterm::{
-    event::{DisableMouseCapture, EnableMouseCapture},
+    event::{DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -14,26 +24,57 @@ use ratatui::Terminal;
 use std::io::{self, stdout};
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut config = Config::load().unwrap_or_else(|e| {
+        eprintln!("Warning: Could not load config: {}", e);
+        Config::default()
+    });
+    match config.apply_args(std::env::args().skip(1)) {
+        Ok(true) => {}
+        Ok(false) => {
+            println!("{}", config::USAGE);
+            return Ok(());
+        }
+        Err(e) => {
+            eprintln!("Error: {}\n\n{}", e, config::USAGE);
+            std::process::exit(2);
+        }
+    }
+
     enable_raw_mode()?;
     let mut stdout = stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture, EnableBracketedPaste)?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
     let mut app = App::new();
+    // Needed before loading so phases missed while closed follow the policy
+    app.pomodoro.policy = config.transitions;
     app.load_state().unwrap_or_else(|e| {
         eprintln!("Warning: Could not load saved state: {}", e);
     });
+    app.load_history().unwrap_or_else(|e| {
+        eprintln!("Warning: Could not load session history: {}", e);
+    });
+    let settings = config.pomodoro_settings(app.pomodoro.settings);
+    app.pomodoro.set_settings(settings);
+    if let Some(goal) = config.daily_goal {
+        app.daily_goal = goal;
+    }
+    if let Some(length) = config.max_title_length {
+        app.max_title_length = length as usize;
+    }
+    app.max_depth = config.max_depth.map(|depth| depth as usize);
     // Save tasks to txt file on startup
     let _ = app.save_tasks_to_txt();
 
-    let result = run_app(&mut terminal, &mut app);
+    let result = run_app(&mut terminal, &mut app, &config);
 
     disable_raw_mode()?;
     execute!(
         terminal.backend_mut(),
         LeaveAlternateScreen,
-        DisableMouseCapture
+        DisableMouseCapture,
+        DisableBracketedPaste
     )?;
     terminal.show_cursor()?;
 
@@ -47,15 +88,13 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 fn run_app(
     terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
     app: &mut App,
+    config: &Config,
 ) -> Result<(), Box<dyn std::error::Error>> {
     loop {
-        // Ensure pomodoro state matches duration before rendering
-        app.pomodoro.sync_state_with_duration();
-        
-        // Hide notification after 1 second
-        if let Some(notif_time) = app.save_notification_time {
-            if notif_time.elapsed().as_secs() >= 1 {
-                app.save_notification_time = None;
+        // Hide notification once its time is up
+        if let Some(notification) = &app.notification {
+            if notification.shown_at.elapsed() >= notification.duration {
+                app.notification = None;
             }
         }
         
@@ -65,7 +104,24 @@ fn run_app(
             break;
         }
 
-        app.pomodoro.update();
+        if let Some(finished) = app.update_pomodoro() {
+            config.notifications.notify(&notify::PhaseChange {
+                finished: finished.state,
+                next: app.pomodoro.state,
+                cycles: app.pomodoro.cycles,
+                task: app.focused_task().map(|t| t.title.as_str()),
+            });
+        }
+        if app.pomodoro.take_overtime_alert() {
+            config.notifications.overtime();
+        }
+        if app.pomodoro.break_reminder_due(chrono::Local::now()) {
+            config.notifications.remind(app.pomodoro.state);
+        }
+        for event in app.take_events() {
+            config.hooks.run(&event);
+        }
+        let _ = app.save_history();
     }
 
     Ok(())
@@ -0,0 +1,222 @@
+use crate::app::PomodoroState;
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TerminalNotification {
+    /// `OSC 9`, understood by iTerm2, WezTerm, Windows Terminal and others.
+    Osc9,
+    /// `OSC 777`, understood by urxvt, foot, Ghostty and VTE-based terminals.
+    Osc777,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DesktopNotifier {
+    /// Runs `notify-send` from libnotify.
+    NotifySend,
+    /// Calls `org.freedesktop.Notifications.Notify` on the session bus via `gdbus`.
+    Dbus,
+}
+
+/// Message templates shown when each phase ends. `{phase}`, `{next}`,
+/// `{cycles}` and `{task}` are replaced with details of the transition.
+/// `break_reminder` is repeated while a break waits to be started, and
+/// `overtime` is sent when an overtime work session passes zero.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PhaseMessages {
+    pub work: String,
+    pub short_break: String,
+    pub long_break: String,
+    pub break_reminder: String,
+    pub overtime: String,
+}
+
+impl Default for PhaseMessages {
+    fn default() -> Self {
+        Self {
+            work: "Work session done, time for a {next}.".to_string(),
+            short_break: "Break's over, back to work.".to_string(),
+            long_break: "Long break's over, back to work.".to_string(),
+            break_reminder: "Your {phase} hasn't started yet.".to_string(),
+            overtime: "Time's up, now in overtime. Press d to take a break.".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NotificationConfig {
+    pub bell: bool,
+    pub terminal: Option<TerminalNotification>,
+    pub desktop: Option<DesktopNotifier>,
+    pub messages: PhaseMessages,
+}
+
+impl Default for NotificationConfig {
+    fn default() -> Self {
+        Self {
+            bell: true,
+            terminal: None,
+            desktop: None,
+            messages: PhaseMessages::default(),
+        }
+    }
+}
+
+/// Details of a phase transition used to fill in message templates.
+#[derive(Debug, Clone, Copy)]
+pub struct PhaseChange<'a> {
+    pub finished: PomodoroState,
+    pub next: PomodoroState,
+    pub cycles: usize,
+    pub task: Option<&'a str>,
+}
+
+pub fn phase_name(state: PomodoroState) -> &'static str {
+    match state {
+        PomodoroState::Work => "Work",
+        PomodoroState::ShortBreak => "Short Break",
+        PomodoroState::LongBreak => "Long Break",
+    }
+}
+
+impl NotificationConfig {
+    pub fn message(&self, change: &PhaseChange) -> String {
+        let template = match change.finished {
+            PomodoroState::Work => &self.messages.work,
+            PomodoroState::ShortBreak => &self.messages.short_break,
+            PomodoroState::LongBreak => &self.messages.long_break,
+        };
+        template
+            .replace("{phase}", phase_name(change.finished))
+            .replace("{next}", &phase_name(change.next).to_lowercase())
+            .replace("{cycles}", &change.cycles.to_string())
+            .replace("{task}", change.task.unwrap_or(""))
+    }
+
+    /// Sends the configured notifications for a phase transition.
+    pub fn notify(&self, change: &PhaseChange) {
+        let message = self.message(change);
+        let title = format!("tsk: {} finished", phase_name(change.finished));
+        self.send(&title, &message);
+    }
+
+    /// Reminds that the break in `state` is waiting to be started.
+    pub fn remind(&self, state: PomodoroState) {
+        let message = self
+            .messages
+            .break_reminder
+            .replace("{phase}", &phase_name(state).to_lowercase());
+        self.send("tsk: break waiting", &message);
+    }
+
+    /// Tells that an overtime work session has passed its planned length.
+    pub fn overtime(&self) {
+        self.send("tsk: overtime", &self.messages.overtime);
+    }
+
+    /// Failures are ignored so a missing notifier never interrupts the timer.
+    fn send(&self, title: &str, message: &str) {
+        let mut sequence = String::new();
+        if self.bell {
+            sequence.push('\x07');
+        }
+        match self.terminal {
+            Some(TerminalNotification::Osc9) => {
+                sequence.push_str(&format!("\x1b]9;{}\x07", sanitize(message)));
+            }
+            Some(TerminalNotification::Osc777) => {
+                sequence.push_str(&format!("\x1b]777;notify;{};{}\x07", sanitize(title), sanitize(message)));
+            }
+            None => {}
+        }
+        if !sequence.is_empty() {
+            let mut stdout = std::io::stdout();
+            let _ = stdout.write_all(sequence.as_bytes());
+            let _ = stdout.flush();
+        }
+
+        if let Some(desktop) = self.desktop {
+            let mut command = match desktop {
+                DesktopNotifier::NotifySend => {
+                    let mut command = Command::new("notify-send");
+                    command.args(["--app-name=tsk", title, message]);
+                    command
+                }
+                DesktopNotifier::Dbus => {
+                    let mut command = Command::new("gdbus");
+                    command.args([
+                        "call",
+                        "--session",
+                        "--dest=org.freedesktop.Notifications",
+                        "--object-path=/org/freedesktop/Notifications",
+                        "--method=org.freedesktop.Notifications.Notify",
+                        "tsk",
+                        "0",
+                        "",
+                        title,
+                        message,
+                        "[]",
+                        "{}",
+                        "-1",
+                    ]);
+                    command
+                }
+            };
+            let child = command
+                .stdin(Stdio::null())
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .spawn();
+            if let Ok(mut child) = child {
+                // Reap the child without blocking the UI
+                std::thread::spawn(move || {
+                    let _ = child.wait();
+                });
+            }
+        }
+    }
+}
+
+/// Strips characters that would end an OSC sequence early.
+fn sanitize(text: &str) -> String {
+    text.chars()
+        .filter(|c| !c.is_control())
+        .map(|c| if c == ';' { ',' } else { c })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_message_templates() {
+        let mut config = NotificationConfig::default();
+        config.messages.work = "{phase} #{cycles} on '{task}' done, {next} now".to_string();
+        let change = PhaseChange {
+            finished: PomodoroState::Work,
+            next: PomodoroState::LongBreak,
+            cycles: 4,
+            task: Some("Report"),
+        };
+        assert_eq!(config.message(&change), "Work #4 on 'Report' done, long break now");
+
+        let change = PhaseChange {
+            finished: PomodoroState::ShortBreak,
+            next: PomodoroState::Work,
+            cycles: 1,
+            task: None,
+        };
+        assert_eq!(config.message(&change), "Break's over, back to work.");
+    }
+
+    #[test]
+    fn test_sanitize() {
+        assert_eq!(sanitize("a;b\x07c\n"), "a,bc");
+    }
+}
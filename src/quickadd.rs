@@ -0,0 +1,171 @@
+use crate::app::Priority;
+use crate::dateparse::parse_date;
+use chrono::NaiveDate;
+
+/// A task title with the quick-add tokens taken out of it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QuickAdd {
+    pub title: String,
+    pub tags: Vec<String>,
+    pub priority: Option<Priority>,
+    pub due: Option<NaiveDate>,
+    pub estimate: Option<u32>,
+}
+
+/// Splits a typed task into its title and inline tokens:
+///
+/// - `#tag` adds a tag
+/// - `!low`, `!medium`, `!high`, `!urgent` (or `!!` to `!!!!` for medium to
+///   urgent) set the priority
+/// - `due:fri` sets the due date; any expression [`parse_date`] accepts
+///   works, with `_` standing in for spaces (`due:next_week`)
+/// - `~3` sets the estimate in pomodoros
+///
+/// The remaining words, in order, make up the title. A lone `!`, and `~`
+/// not followed by a number, are kept as words. A leading `\` keeps a word
+/// as typed (`\#123` is the word `#123`). A malformed token is reported
+/// rather than left in the title.
+pub fn parse_quick_add(input: &str, today: NaiveDate) -> Result<QuickAdd, String> {
+    let mut parsed = QuickAdd::default();
+    let mut words = Vec::new();
+
+    for word in input.split_whitespace() {
+        match classify(word) {
+            Word::Tag => push_tag(&mut parsed.tags, parse_tag(word)?),
+            Word::Priority(level) => {
+                parsed.priority = Some(parse_priority(level).ok_or_else(|| {
+                    format!("Unknown priority '{}', use !low, !medium, !high or !urgent", word)
+                })?);
+            }
+            Word::Due(expression) => {
+                if expression.is_empty() {
+                    return Err("Missing date after 'due:'".to_string());
+                }
+                parsed.due = Some(parse_date(&expression.replace('_', " "), today)?);
+            }
+            Word::Estimate(count) => match count.parse::<u32>() {
+                Ok(n) if n > 0 => parsed.estimate = Some(n),
+                _ => return Err(format!("Invalid estimate '{}', use a number of pomodoros like ~3", word)),
+            },
+            Word::Literal(text) => words.push(text),
+        }
+    }
+
+    parsed.title = words.join(" ");
+    Ok(parsed)
+}
+
+/// Puts a `\` before each word of `title` that [`parse_quick_add`] would
+/// otherwise take as a token, so the title reads back unchanged.
+pub fn escape_tokens(title: &str) -> String {
+    let mut escaped = String::with_capacity(title.len());
+    let mut word_start = true;
+    for (idx, c) in title.char_indices() {
+        if word_start && !c.is_whitespace() {
+            let word = title[idx..].split_whitespace().next().unwrap_or_default();
+            if !matches!(classify(word), Word::Literal(text) if text == word) {
+                escaped.push('\\');
+            }
+        }
+        word_start = c.is_whitespace();
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// What a typed word stands for, with its token prefix taken off.
+enum Word<'a> {
+    Tag,
+    Priority(&'a str),
+    Due(&'a str),
+    Estimate(&'a str),
+    Literal(&'a str),
+}
+
+fn classify(word: &str) -> Word<'_> {
+    if let Some(text) = word.strip_prefix('\\') {
+        Word::Literal(text)
+    } else if word.starts_with('#') {
+        Word::Tag
+    } else if let Some(level) = word.strip_prefix('!').filter(|level| !level.is_empty()) {
+        Word::Priority(level)
+    } else if let Some(expression) = word.strip_prefix("due:") {
+        Word::Due(expression)
+    } else if let Some(count) = word.strip_prefix('~').filter(|count| count.starts_with(|c: char| c.is_ascii_digit())) {
+        Word::Estimate(count)
+    } else {
+        Word::Literal(word)
+    }
+}
+
+/// Checks a tag typed with or without its leading `#`, returning it without.
+pub fn parse_tag(word: &str) -> Result<&str, String> {
+    let tag = word.strip_prefix('#').unwrap_or(word);
+    if tag.is_empty() || tag.contains('#') {
+        return Err(format!("Invalid tag '{}'", word));
+    }
+    Ok(tag)
+}
+
+/// Adds `tag` unless the list already has it, ignoring case.
+pub fn push_tag(tags: &mut Vec<String>, tag: &str) {
+    if !tags.iter().any(|t| t.to_lowercase() == tag.to_lowercase()) {
+        tags.push(tag.to_string());
+    }
+}
+
+fn parse_priority(level: &str) -> Option<Priority> {
+    match level.to_lowercase().as_str() {
+        "low" => Some(Priority::Low),
+        "!" | "med" | "medium" => Some(Priority::Medium),
+        "!!" | "high" => Some(Priority::High),
+        "!!!" | "urgent" => Some(Priority::Urgent),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokens_are_stripped() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 16).unwrap();
+        let parsed = parse_quick_add("Write  #work report !high due:next_week ~3 #Work #q4", today).unwrap();
+        assert_eq!(parsed.title, "Write report");
+        assert_eq!(parsed.tags, vec!["work", "q4"]);
+        assert_eq!(parsed.priority, Some(Priority::High));
+        assert_eq!(parsed.due, NaiveDate::from_ymd_opt(2026, 10, 19));
+        assert_eq!(parsed.estimate, Some(3));
+
+        let parsed = parse_quick_add("Ship v2!! now !!", today).unwrap();
+        assert_eq!(parsed.title, "Ship v2!! now");
+        assert_eq!(parsed.priority, Some(Priority::Medium));
+    }
+
+    #[test]
+    fn test_words_kept_in_title() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 16).unwrap();
+        let parsed = parse_quick_add(r"Fix issue \#123 Wow ! Read ~/notes \due:soon \\x ~", today).unwrap();
+        assert_eq!(parsed.title, r"Fix issue #123 Wow ! Read ~/notes due:soon \x ~");
+        assert_eq!(parsed, QuickAdd { title: parsed.title.clone(), ..QuickAdd::default() });
+
+        let title = r"Fix #123 !now ~2 due:x \path ~/notes !";
+        assert_eq!(escape_tokens(title), r"Fix \#123 \!now \~2 \due:x \\path ~/notes !");
+        assert_eq!(parse_quick_add(&escape_tokens(title), today).unwrap().title, title);
+    }
+
+    #[test]
+    fn test_malformed_tokens() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 16).unwrap();
+        assert_eq!(parse_quick_add("Call #", today), Err("Invalid tag '#'".to_string()));
+        assert!(parse_quick_add("Call !soon", today).is_err());
+        assert!(parse_quick_add("Call ~0", today).is_err());
+        assert!(parse_quick_add("Call ~2h", today).is_err());
+        assert!(parse_quick_add("Call due:", today).is_err());
+        assert_eq!(
+            parse_quick_add("Call due:someday", today),
+            Err("Unrecognised date 'someday'".to_string())
+        );
+    }
+}
@@ -0,0 +1,144 @@
+use crate::app::PomodoroState;
+use crate::history::{SessionOutcome, SessionRecord};
+use chrono::{Duration, NaiveDate};
+use std::collections::{BTreeSet, HashMap};
+
+/// Focus statistics derived from the session history.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stats {
+    /// Focus minutes per day, oldest first, ending today.
+    pub daily_minutes: Vec<(NaiveDate, u64)>,
+    pub today_pomodoros: usize,
+    pub current_streak: usize,
+    pub longest_streak: usize,
+}
+
+impl Stats {
+    pub fn from_history(history: &[SessionRecord], today: NaiveDate, days: usize) -> Self {
+        let work_sessions = || history.iter().filter(|r| r.phase == PomodoroState::Work);
+
+        let mut seconds_by_day: HashMap<NaiveDate, i64> = HashMap::new();
+        for record in work_sessions() {
+            // Paused time isn't focus, so the span from start to end isn't used
+            let seconds = record.active_seconds.unwrap_or_default().max(0);
+            *seconds_by_day.entry(record.started_at.date_naive()).or_default() += seconds;
+        }
+        let daily_minutes = (0..days)
+            .rev()
+            .map(|offset| {
+                let date = today - Duration::days(offset as i64);
+                let seconds = seconds_by_day.get(&date).copied().unwrap_or(0);
+                (date, (seconds / 60) as u64)
+            })
+            .collect();
+
+        let completed_days: BTreeSet<NaiveDate> = work_sessions()
+            .filter(|r| r.outcome == SessionOutcome::Completed)
+            .map(|r| r.ended_at.date_naive())
+            .collect();
+        let today_pomodoros = work_sessions()
+            .filter(|r| r.outcome == SessionOutcome::Completed && r.ended_at.date_naive() == today)
+            .count();
+
+        Self {
+            daily_minutes,
+            today_pomodoros,
+            current_streak: current_streak(&completed_days, today),
+            longest_streak: longest_streak(&completed_days),
+        }
+    }
+}
+
+/// Consecutive days with a completed pomodoro, ending today. A streak that
+/// reached yesterday still counts until today is over.
+fn current_streak(days: &BTreeSet<NaiveDate>, today: NaiveDate) -> usize {
+    let mut date = if days.contains(&today) {
+        today
+    } else {
+        today - Duration::days(1)
+    };
+    let mut streak = 0;
+    while days.contains(&date) {
+        streak += 1;
+        date -= Duration::days(1);
+    }
+    streak
+}
+
+fn longest_streak(days: &BTreeSet<NaiveDate>) -> usize {
+    let mut longest = 0;
+    let mut streak = 0;
+    let mut previous: Option<NaiveDate> = None;
+    for &date in days {
+        streak = match previous {
+            Some(prev) if date - prev == Duration::days(1) => streak + 1,
+            _ => 1,
+        };
+        longest = longest.max(streak);
+        previous = Some(date);
+    }
+    longest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Local, TimeZone};
+
+    fn work(date: NaiveDate, minutes: i64, outcome: SessionOutcome) -> SessionRecord {
+        let started_at = Local.from_local_datetime(&date.and_hms_opt(9, 0, 0).unwrap()).unwrap();
+        SessionRecord {
+            phase: PomodoroState::Work,
+            started_at,
+            ended_at: started_at + Duration::minutes(minutes),
+            outcome,
+            task_id: None,
+            task_title: None,
+            mode: Default::default(),
+            planned_seconds: Some(minutes * 60),
+            active_seconds: Some(minutes * 60),
+        }
+    }
+
+    #[test]
+    fn test_daily_minutes_and_today() {
+        let today = NaiveDate::from_ymd_opt(2026, 3, 10).unwrap();
+        let yesterday = today - Duration::days(1);
+        // Paused for two hours in the middle of 25 minutes of work
+        let mut paused = work(today, 25, SessionOutcome::Completed);
+        paused.ended_at += Duration::hours(2);
+        let history = vec![
+            paused,
+            work(today, 10, SessionOutcome::Reset),
+            work(yesterday, 50, SessionOutcome::Completed),
+        ];
+        let stats = Stats::from_history(&history, today, 30);
+        assert_eq!(stats.daily_minutes.len(), 30);
+        assert_eq!(stats.daily_minutes[29], (today, 35));
+        assert_eq!(stats.daily_minutes[28], (yesterday, 50));
+        assert_eq!(stats.daily_minutes[0].1, 0);
+        assert_eq!(stats.today_pomodoros, 1);
+    }
+
+    #[test]
+    fn test_streaks() {
+        let today = NaiveDate::from_ymd_opt(2026, 3, 10).unwrap();
+        let day = |offset: i64| today - Duration::days(offset);
+        let history = vec![
+            // Three-day streak a week ago
+            work(day(9), 25, SessionOutcome::Completed),
+            work(day(8), 25, SessionOutcome::Completed),
+            work(day(7), 25, SessionOutcome::Completed),
+            // Reset sessions don't count towards streaks
+            work(day(3), 25, SessionOutcome::Reset),
+            work(day(2), 25, SessionOutcome::Completed),
+            work(day(1), 25, SessionOutcome::Completed),
+        ];
+        let stats = Stats::from_history(&history, today, 30);
+        assert_eq!(stats.current_streak, 2);
+        assert_eq!(stats.longest_streak, 3);
+
+        let stats = Stats::from_history(&history, day(-1), 30);
+        assert_eq!(stats.current_streak, 0);
+    }
+}
@@ -65,6 +65,10 @@ impl ColorPalette {
             56 => Color::Rgb(255, 255, 138), // Vesper yellow
             57 => Color::Rgb(197, 197, 197), // Vesper gray
             58 => Color::Rgb(86, 86, 86),   // Vesper dark
+            59 => Color::Rgb(191, 97, 106),  // Blue Ridge red
+            60 => Color::Rgb(230, 126, 128), // Everforest red
+            61 => Color::Rgb(247, 118, 142), // Tokyo Night red
+            62 => Color::Rgb(255, 128, 128), // Vesper red
             _ => Color::Reset,
         }
     }
@@ -96,6 +100,15 @@ pub struct Theme {
     pub task_completed: u8,
     pub input_prompt: u8,
     pub secondary: u8,
+    pub task_over_estimate: u8,
+    pub input_warning: u8,
+    pub priority_low: u8,
+    pub priority_medium: u8,
+    pub priority_high: u8,
+    pub priority_urgent: u8,
+    pub task_overdue: u8,
+    pub task_due_today: u8,
+    pub tag_chip: u8,
 }
 
 impl Theme {
@@ -134,6 +147,42 @@ impl Theme {
     pub fn get_secondary(&self) -> Color {
         ColorPalette::get_color(self.secondary)
     }
+
+    pub fn get_task_over_estimate(&self) -> Color {
+        ColorPalette::get_color(self.task_over_estimate)
+    }
+
+    pub fn get_input_warning(&self) -> Color {
+        ColorPalette::get_color(self.input_warning)
+    }
+
+    pub fn get_priority_low(&self) -> Color {
+        ColorPalette::get_color(self.priority_low)
+    }
+
+    pub fn get_priority_medium(&self) -> Color {
+        ColorPalette::get_color(self.priority_medium)
+    }
+
+    pub fn get_priority_high(&self) -> Color {
+        ColorPalette::get_color(self.priority_high)
+    }
+
+    pub fn get_priority_urgent(&self) -> Color {
+        ColorPalette::get_color(self.priority_urgent)
+    }
+
+    pub fn get_task_overdue(&self) -> Color {
+        ColorPalette::get_color(self.task_overdue)
+    }
+
+    pub fn get_task_due_today(&self) -> Color {
+        ColorPalette::get_color(self.task_due_today)
+    }
+
+    pub fn get_tag_chip(&self) -> Color {
+        ColorPalette::get_color(self.tag_chip)
+    }
 }
 
 impl Theme {
@@ -148,6 +197,15 @@ impl Theme {
             task_completed: 8,  // DarkGray
             input_prompt: 6,   // Cyan
             secondary: 11,     // LightYellow
+            task_over_estimate: 1,  // Red
+            input_warning: 1,  // Red
+            priority_low: 4,  // Blue
+            priority_medium: 3,  // Yellow
+            priority_high: 9,  // LightRed
+            priority_urgent: 1,  // Red
+            task_overdue: 1,  // Red
+            task_due_today: 11,  // LightYellow
+            tag_chip: 5,  // Magenta
         }
     }
 
@@ -162,6 +220,15 @@ impl Theme {
             task_completed: 8,  // DarkGray
             input_prompt: 14,   // LightCyan
             secondary: 3,      // Yellow
+            task_over_estimate: 9,  // LightRed
+            input_warning: 9,  // LightRed
+            priority_low: 12,  // LightBlue
+            priority_medium: 11,  // LightYellow
+            priority_high: 13,  // LightMagenta
+            priority_urgent: 9,  // LightRed
+            task_overdue: 9,  // LightRed
+            task_due_today: 11,  // LightYellow
+            tag_chip: 13,  // LightMagenta
         }
     }
 
@@ -176,6 +243,15 @@ impl Theme {
             task_completed: 8,  // DarkGray
             input_prompt: 4,   // Blue
             secondary: 8,     // DarkGray
+            task_over_estimate: 1,  // Red
+            input_warning: 1,  // Red
+            priority_low: 4,  // Blue
+            priority_medium: 3,  // Yellow
+            priority_high: 5,  // Magenta
+            priority_urgent: 1,  // Red
+            task_overdue: 1,  // Red
+            task_due_today: 5,  // Magenta
+            tag_chip: 5,  // Magenta
         }
     }
 
@@ -190,6 +266,15 @@ impl Theme {
             task_completed: 8,  // DarkGray
             input_prompt: 7,   // White
             secondary: 8,     // DarkGray
+            task_over_estimate: 7,  // White
+            input_warning: 7,  // White
+            priority_low: 8,  // DarkGray
+            priority_medium: 7,  // White
+            priority_high: 7,  // White
+            priority_urgent: 7,  // White
+            task_overdue: 7,  // White
+            task_due_today: 7,  // White
+            tag_chip: 7,  // White
         }
     }
 
@@ -204,6 +289,15 @@ impl Theme {
             task_completed: 8,  // DarkGray
             input_prompt: 12,  // LightBlue
             secondary: 14,     // LightCyan
+            task_over_estimate: 9,  // LightRed
+            input_warning: 9,  // LightRed
+            priority_low: 12,  // LightBlue
+            priority_medium: 14,  // LightCyan
+            priority_high: 11,  // LightYellow
+            priority_urgent: 9,  // LightRed
+            task_overdue: 9,  // LightRed
+            task_due_today: 11,  // LightYellow
+            tag_chip: 12,  // LightBlue
         }
     }
 
@@ -218,6 +312,15 @@ impl Theme {
             task_completed: 22,  // Blue Ridge dark gray
             input_prompt: 16,   // Blue Ridge cyan
             secondary: 23,      // Blue Ridge light beige
+            task_over_estimate: 59,  // Blue Ridge red
+            input_warning: 59,  // Blue Ridge red
+            priority_low: 18,  // Blue Ridge blue
+            priority_medium: 20,  // Blue Ridge gold
+            priority_high: 19,  // Blue Ridge magenta
+            priority_urgent: 59,  // Blue Ridge red
+            task_overdue: 59,  // Blue Ridge red
+            task_due_today: 20,  // Blue Ridge gold
+            tag_chip: 19,  // Blue Ridge magenta
         }
     }
 
@@ -232,6 +335,15 @@ impl Theme {
             task_completed: 30,  // Dotrb dark
             input_prompt: 24,   // Dotrb purple
             secondary: 31,      // Dotrb peach
+            task_over_estimate: 27,  // Dotrb magenta
+            input_warning: 27,  // Dotrb magenta
+            priority_low: 26,  // Dotrb blue
+            priority_medium: 28,  // Dotrb tan
+            priority_high: 24,  // Dotrb purple
+            priority_urgent: 27,  // Dotrb magenta
+            task_overdue: 27,  // Dotrb magenta
+            task_due_today: 31,  // Dotrb peach
+            tag_chip: 27,  // Dotrb magenta
         }
     }
 
@@ -246,6 +358,15 @@ impl Theme {
             task_completed: 38,  // Everforest dark
             input_prompt: 32,   // Everforest green
             secondary: 36,      // Everforest yellow
+            task_over_estimate: 60,  // Everforest red
+            input_warning: 60,  // Everforest red
+            priority_low: 34,  // Everforest teal
+            priority_medium: 36,  // Everforest yellow
+            priority_high: 35,  // Everforest pink
+            priority_urgent: 60,  // Everforest red
+            task_overdue: 60,  // Everforest red
+            task_due_today: 36,  // Everforest yellow
+            tag_chip: 35,  // Everforest pink
         }
     }
 
@@ -260,6 +381,15 @@ impl Theme {
             task_completed: 44,  // Mars dark
             input_prompt: 39,   // Mars gray
             secondary: 31,     // Mars peach (reuse Dotrb peach)
+            task_over_estimate: 42,  // Mars pink
+            input_warning: 42,  // Mars pink
+            priority_low: 41,  // Mars blue-gray
+            priority_medium: 43,  // Mars beige
+            priority_high: 31,  // Mars peach (reuse Dotrb peach)
+            priority_urgent: 42,  // Mars pink
+            task_overdue: 42,  // Mars pink
+            task_due_today: 31,  // Mars peach (reuse Dotrb peach)
+            tag_chip: 42,  // Mars pink
         }
     }
 
@@ -274,6 +404,15 @@ impl Theme {
             task_completed: 51,  // Tokyo Night dark
             input_prompt: 45,   // Tokyo Night cyan
             secondary: 49,      // Tokyo Night gold
+            task_over_estimate: 61,  // Tokyo Night red
+            input_warning: 61,  // Tokyo Night red
+            priority_low: 47,  // Tokyo Night blue
+            priority_medium: 49,  // Tokyo Night gold
+            priority_high: 48,  // Tokyo Night purple
+            priority_urgent: 61,  // Tokyo Night red
+            task_overdue: 61,  // Tokyo Night red
+            task_due_today: 49,  // Tokyo Night gold
+            tag_chip: 48,  // Tokyo Night purple
         }
     }
 
@@ -288,6 +427,15 @@ impl Theme {
             task_completed: 58,  // Vesper dark
             input_prompt: 52,   // Vesper cyan
             secondary: 56,      // Vesper yellow
+            task_over_estimate: 62,  // Vesper red
+            input_warning: 62,  // Vesper red
+            priority_low: 54,  // Vesper blue
+            priority_medium: 56,  // Vesper yellow
+            priority_high: 55,  // Vesper magenta
+            priority_urgent: 62,  // Vesper red
+            task_overdue: 62,  // Vesper red
+            task_due_today: 56,  // Vesper yellow
+            tag_chip: 55,  // Vesper magenta
         }
     }
 
@@ -1,17 +1,27 @@
-use crate::app::{App, InputMode, PomodoroState, Task};
+use crate::app::{find_ignoring_case, listed_siblings, App, DateField, InputMode, PomodoroState, Priority, Task, TimerMode};
+use crate::dateparse::parse_date;
+use chrono::{Datelike, Local, NaiveDate};
 use ratatui::{
-    layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::{Modifier, Style},
+    layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
+    style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{block::Title, Block, Borders, Gauge, List, ListItem, Paragraph, Wrap},
+    widgets::{
+        block::Title, BarChart, Block, Borders, Clear, Gauge, List, ListItem, ListState, Paragraph, Scrollbar,
+        ScrollbarOrientation, ScrollbarState, Wrap,
+    },
     Frame,
 };
+use unicode_width::UnicodeWidthStr;
 
-pub fn render(app: &App, f: &mut Frame) {
+pub fn render(app: &mut App, f: &mut Frame) {
     if app.input_mode == InputMode::Menu {
         render_menu(app, f);
         return;
     }
+    if app.input_mode == InputMode::Stats {
+        render_stats(app, f);
+        return;
+    }
 
     let chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -31,14 +41,21 @@ pub fn render(app: &App, f: &mut Frame) {
     // Render version in bottom right corner
     render_version(f);
     
-    // Render save notification if active
-    if app.save_notification_time.is_some() {
-        render_save_notification(app, f);
+    // Render notification if active
+    if let Some(notification) = &app.notification {
+        render_notification(app, &notification.message, f);
     }
 }
 
 fn render_clock(app: &App, f: &mut Frame, area: Rect) {
-    let time = app.get_current_time();
+    let mut time = app.get_current_time();
+    let (due_today, overdue) = app.due_counts(Local::now().date_naive());
+    if due_today > 0 {
+        time.push_str(&format!("  |  {} due today", due_today));
+    }
+    if overdue > 0 {
+        time.push_str(&format!("  |  {} overdue", overdue));
+    }
     let clock = Paragraph::new(time)
         .block(Block::default().borders(Borders::ALL).title("Clock"))
         .style(Style::default().fg(app.theme.get_clock()))
@@ -48,9 +65,16 @@ fn render_clock(app: &App, f: &mut Frame, area: Rect) {
 
 fn render_pomodoro(app: &App, f: &mut Frame, area: Rect) {
     let timer = &app.pomodoro;
-    let remaining_seconds = timer.get_remaining_seconds();
-    let minutes = remaining_seconds / 60;
-    let seconds = remaining_seconds % 60;
+    let flowing = timer.is_open_ended() && timer.settings.mode == TimerMode::Flowtime;
+    let overtime = timer.is_in_overtime();
+    // Flowtime work counts up; overtime counts up past zero
+    let shown_seconds = if flowing {
+        timer.get_elapsed_seconds()
+    } else {
+        timer.get_remaining_seconds().abs()
+    };
+    let minutes = shown_seconds / 60;
+    let seconds = shown_seconds % 60;
 
     let state_text = match timer.state {
         PomodoroState::Work => "Work",
@@ -58,8 +82,14 @@ fn render_pomodoro(app: &App, f: &mut Frame, area: Rect) {
         PomodoroState::LongBreak => "Long Break",
     };
 
-    let timer_text = format!("{:02}:{:02}", minutes, seconds);
+    let timer_text = if overtime {
+        format!("+{:02}:{:02}", minutes, seconds)
+    } else {
+        format!("{:02}:{:02}", minutes, seconds)
+    };
     let status_text = match timer.timer_state {
+        crate::app::TimerState::Running if overtime => "Overtime",
+        crate::app::TimerState::Running if flowing => "Flow",
         crate::app::TimerState::Running => "Running",
         crate::app::TimerState::Paused => "Paused",
         crate::app::TimerState::Stopped => "Stopped",
@@ -87,10 +117,14 @@ fn render_pomodoro(app: &App, f: &mut Frame, area: Rect) {
     f.render_widget(block, area);
 
     let text_color = app.theme.get_secondary();
+    let focus_label = match app.focused_task() {
+        Some(task) => format!("Focus: {}", task.title),
+        None => String::new(),
+    };
     let gauge = Gauge::default()
         .gauge_style(Style::default().fg(text_color))
         .percent((progress * 100.0) as u16)
-        .label("");
+        .label(focus_label);
     f.render_widget(gauge, inner_area);
 }
 
@@ -98,94 +132,328 @@ fn is_path_selected(task_idx: usize, path: &[usize], selected_idx: usize, select
     task_idx == selected_idx && path == selected_path
 }
 
+/// Actual against estimated pomodoros, e.g. `●●○○` or `●●●●+1`. Long
+/// estimates fall back to a count so the row doesn't overflow.
+fn estimate_bar(actual: u32, estimate: u32) -> String {
+    if estimate > 10 {
+        return format!("{}/{}🍅", actual, estimate);
+    }
+    let done = actual.min(estimate) as usize;
+    let mut bar = "●".repeat(done) + &"○".repeat(estimate as usize - done);
+    if actual > estimate {
+        bar.push_str(&format!("+{}", actual - estimate));
+    }
+    bar
+}
+
 struct TaskRenderContext {
     task_idx: usize,
     path: Vec<usize>,
     level: usize,
+    /// Whether the row and each of its ancestors below the top level is the
+    /// last of its siblings, outermost first.
+    last_sibling: Vec<bool>,
     selected_idx: usize,
     selected_path: Vec<usize>,
+    focused_task_id: Option<usize>,
+    today: NaiveDate,
+    sort_by_priority: bool,
+    tag_filter: Vec<String>,
+    search_query: String,
     theme: crate::theme::Theme,
 }
 
+/// Short date for task rows: "today", "tomorrow", "Oct 20", or with the
+/// year when it isn't this year.
+fn format_date(date: NaiveDate, today: NaiveDate) -> String {
+    if date == today {
+        "today".to_string()
+    } else if date.pred_opt() == Some(today) {
+        "tomorrow".to_string()
+    } else if date.year() == today.year() {
+        date.format("%b %d").to_string()
+    } else {
+        date.format("%b %d %Y").to_string()
+    }
+}
+
+fn format_focus_time(seconds: u64) -> String {
+    let minutes = seconds / 60;
+    if minutes < 60 {
+        format!("{}m", minutes)
+    } else {
+        format!("{}h{:02}m", minutes / 60, minutes % 60)
+    }
+}
+
+fn priority_marker(priority: Priority, theme: &crate::theme::Theme) -> Option<(&'static str, Color)> {
+    match priority {
+        Priority::None => None,
+        Priority::Low => Some(("!", theme.get_priority_low())),
+        Priority::Medium => Some(("!!", theme.get_priority_medium())),
+        Priority::High => Some(("!!!", theme.get_priority_high())),
+        Priority::Urgent => Some(("!!!!", theme.get_priority_urgent())),
+    }
+}
+
+/// Deepest levels that get their own tree guide column; rows nested deeper
+/// share one `…` column for the levels above so they don't run off the pane.
+const MAX_GUIDE_LEVELS: usize = 8;
+
+/// Tree guides in front of a row, e.g. `│ ├ ` for the second subtask of a
+/// subtask that has more siblings after it.
+fn tree_guides(last_sibling: &[bool]) -> String {
+    let Some((&last, ancestors)) = last_sibling.split_last() else {
+        return String::new();
+    };
+    let mut guides = String::new();
+    let hidden = ancestors.len().saturating_sub(MAX_GUIDE_LEVELS - 1);
+    if hidden > 0 {
+        guides.push_str("… ");
+    }
+    for &ancestor_last in &ancestors[hidden..] {
+        guides.push_str(if ancestor_last { "  " } else { "│ " });
+    }
+    guides.push_str(if last { "└ " } else { "├ " });
+    guides
+}
+
 fn render_task_recursive(
     task: &Task,
     ctx: &TaskRenderContext,
     items: &mut Vec<ListItem>,
 ) {
-    let indent = "  ".repeat(ctx.level);
+    let indent = tree_guides(&ctx.last_sibling);
     let is_selected = is_path_selected(ctx.task_idx, &ctx.path, ctx.selected_idx, &ctx.selected_path);
     let prefix = if task.completed { "[x]" } else { "[ ]" };
+    let overdue = task.is_overdue(ctx.today);
+    let due_today = task.is_due_today(ctx.today);
     let style = if is_selected {
         Style::default()
             .fg(ctx.theme.get_task_selected())
             .add_modifier(Modifier::BOLD | Modifier::REVERSED)
     } else if task.completed {
         Style::default().fg(ctx.theme.get_task_completed())
+    } else if overdue {
+        Style::default().fg(ctx.theme.get_task_overdue()).add_modifier(Modifier::BOLD)
+    } else if due_today {
+        Style::default().fg(ctx.theme.get_task_due_today())
     } else {
         Style::default().fg(ctx.theme.get_task_normal())
     };
 
-    let text = vec![
-        Span::styled(format!("{}{}", indent, prefix), style),
+    let focus_marker = if ctx.focused_task_id == Some(task.id) { "▶ " } else { "" };
+    let fold_marker = match (task.subtasks.is_empty(), task.collapsed) {
+        (true, _) => " ",
+        (false, true) => "▸",
+        (false, false) => "▾",
+    };
+    let mut text = vec![
+        Span::styled(format!("{}{} {}", indent, fold_marker, prefix), style),
         Span::raw(" "),
-        Span::styled(task.title.clone(), style),
     ];
+    if let Some((marker, color)) = priority_marker(task.priority, &ctx.theme) {
+        text.push(Span::styled(format!("{} ", marker), Style::default().fg(color).add_modifier(Modifier::BOLD)));
+    }
+    let highlight = style.fg(ctx.theme.get_secondary()).add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+    text.push(Span::styled(focus_marker, style));
+    match find_ignoring_case(&task.title, &ctx.search_query) {
+        Some((start, end)) => {
+            text.push(Span::styled(task.title[..start].to_string(), style));
+            text.push(Span::styled(task.title[start..end].to_string(), highlight));
+            text.push(Span::styled(task.title[end..].to_string(), style));
+        }
+        None => text.push(Span::styled(task.title.clone(), style)),
+    }
+    for tag in &task.tags {
+        // Tags the list is filtered by stand out from the others
+        let mut chip = Style::default().fg(ctx.theme.get_tag_chip()).add_modifier(Modifier::REVERSED);
+        if ctx.tag_filter.iter().any(|t| t.to_lowercase() == tag.to_lowercase()) {
+            chip = chip.add_modifier(Modifier::BOLD);
+        }
+        let tag_query = ctx.search_query.trim_start_matches('#');
+        if !ctx.search_query.is_empty() && tag.to_lowercase().contains(&tag_query.to_lowercase()) {
+            chip = chip.add_modifier(Modifier::UNDERLINED);
+        }
+        text.push(Span::raw(" "));
+        text.push(Span::styled(format!(" {} ", tag), chip));
+    }
+
+    // Time tracked on this task, rolled up from its subtasks
+    let focus_seconds = task.total_focus_seconds();
+    let pomodoros = task.total_pomodoros();
+    let muted = Style::default().fg(ctx.theme.get_task_completed());
+    if let Some(due) = task.due {
+        let style = if overdue {
+            Style::default().fg(ctx.theme.get_task_overdue()).add_modifier(Modifier::BOLD)
+        } else if due_today {
+            Style::default().fg(ctx.theme.get_task_due_today())
+        } else {
+            muted
+        };
+        text.push(Span::styled(format!("  due {}", format_date(due, ctx.today)), style));
+    }
+    if let Some(scheduled) = task.scheduled {
+        text.push(Span::styled(format!("  on {}", format_date(scheduled, ctx.today)), muted));
+    }
+    if task.collapsed {
+        let (done, total) = task.subtask_progress();
+        text.push(Span::styled(format!("  ({}/{} done)", done, total), muted));
+    }
+    if focus_seconds > 0 {
+        text.push(Span::styled(format!("  {}", format_focus_time(focus_seconds)), muted));
+    }
+    match task.estimate {
+        Some(estimate) => {
+            let style = if pomodoros > estimate {
+                Style::default().fg(ctx.theme.get_task_over_estimate())
+            } else {
+                muted
+            };
+            text.push(Span::styled(format!("  {}", estimate_bar(pomodoros, estimate)), style));
+        }
+        None if pomodoros > 0 => text.push(Span::styled(format!("  {}🍅", pomodoros), muted)),
+        None => {}
+    }
 
     items.push(ListItem::new(Line::from(text)));
 
-    if ctx.level < 4 {
-        for (sub_idx, subtask) in task.subtasks.iter().enumerate() {
+    if !task.collapsed {
+        let shown = listed_siblings(&task.subtasks, ctx.sort_by_priority, &ctx.tag_filter);
+        for (row, &sub_idx) in shown.iter().enumerate() {
             let mut new_path = ctx.path.clone();
             new_path.push(sub_idx);
+            let mut last_sibling = ctx.last_sibling.clone();
+            last_sibling.push(row + 1 == shown.len());
             let new_ctx = TaskRenderContext {
                 task_idx: ctx.task_idx,
                 path: new_path,
                 level: ctx.level + 1,
+                last_sibling,
                 selected_idx: ctx.selected_idx,
                 selected_path: ctx.selected_path.clone(),
+                focused_task_id: ctx.focused_task_id,
+                today: ctx.today,
+                sort_by_priority: ctx.sort_by_priority,
+                tag_filter: ctx.tag_filter.clone(),
+                search_query: ctx.search_query.clone(),
                 theme: ctx.theme,
             };
-            render_task_recursive(subtask, &new_ctx, items);
+            render_task_recursive(&task.subtasks[sub_idx], &new_ctx, items);
         }
     }
 }
 
-fn render_tasks(app: &App, f: &mut Frame, area: Rect) {
+fn render_tasks(app: &mut App, f: &mut Frame, area: Rect) {
     let mut items = Vec::new();
+    let today = Local::now().date_naive();
 
-    for (idx, task) in app.tasks.iter().enumerate() {
+    for idx in listed_siblings(&app.tasks, app.sort_by_priority, &app.tag_filter) {
         let ctx = TaskRenderContext {
             task_idx: idx,
             path: Vec::new(),
             level: 0,
+            last_sibling: Vec::new(),
             selected_idx: app.selected_index,
             selected_path: app.selected_path.clone(),
+            focused_task_id: app.focused_task_id,
+            today,
+            sort_by_priority: app.sort_by_priority,
+            tag_filter: app.tag_filter.clone(),
+            search_query: app.search_query.clone(),
             theme: app.theme,
         };
-        render_task_recursive(task, &ctx, &mut items);
+        render_task_recursive(&app.tasks[idx], &ctx, &mut items);
     }
 
     if items.is_empty() {
+        let message = if app.tasks.is_empty() {
+            "No tasks yet. Press 'a' to add a task."
+        } else {
+            "No tasks with these tags. Press 'T' to change the filter."
+        };
         items.push(ListItem::new(Line::from(Span::styled(
-            "No tasks yet. Press 'a' to add a task.",
+            message,
             Style::default().fg(app.theme.get_task_completed()),
         ))));
     }
 
     let tasks_list = List::new(items)
-        .block(Block::default().borders(Borders::ALL).title("Tasks"));
+        .block(Block::default().borders(Borders::ALL).title(tasks_title(app, area.width)));
+
+    // The list scrolls just enough to keep the selected row in view
+    let row_count = app.visible_row_count();
+    let selected_row = (row_count > 0).then(|| app.selected_row());
+    let mut list_state = ListState::default()
+        .with_offset(app.task_list_offset)
+        .with_selected(selected_row);
+    app.task_list_height = area.height.saturating_sub(2) as usize;
+    f.render_stateful_widget(tasks_list, area, &mut list_state);
+    app.task_list_offset = list_state.offset();
+
+    if row_count > app.task_list_height {
+        let mut scrollbar_state = ScrollbarState::new(row_count)
+            .viewport_content_length(app.task_list_height)
+            .position(selected_row.unwrap_or(0));
+        let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .style(Style::default().fg(app.theme.get_task_completed()));
+        f.render_stateful_widget(
+            scrollbar,
+            area.inner(Margin { vertical: 1, horizontal: 0 }),
+            &mut scrollbar_state,
+        );
+    }
+}
 
-    f.render_widget(tasks_list, area);
+/// "Tasks", followed by the path to the selected subtask. The outermost
+/// parents are dropped first when the path doesn't fit.
+fn tasks_title(app: &App, width: u16) -> String {
+    let mut label = if app.sort_by_priority { "Tasks (by priority)" } else { "Tasks" }.to_string();
+    if !app.tag_filter.is_empty() {
+        let tags: Vec<String> = app.tag_filter.iter().map(|tag| format!("#{}", tag)).collect();
+        label.push_str(&format!(" [{}]", tags.join(" ")));
+    }
+    if !app.search_query.is_empty() {
+        let matches = app.search_matches();
+        let current = (app.selected_index, app.selected_path.clone());
+        match matches.iter().position(|m| *m == current) {
+            Some(idx) => label.push_str(&format!(" /{} ({}/{})", app.search_query, idx + 1, matches.len())),
+            None if matches.is_empty() => label.push_str(&format!(" /{} (no matches)", app.search_query)),
+            None => label.push_str(&format!(" /{} ({} matches)", app.search_query, matches.len())),
+        }
+    }
+    let ancestors = app.selected_ancestor_titles();
+    if ancestors.is_empty() {
+        return label;
+    }
+    let available = (width as usize).saturating_sub(label.width() + 6);
+    let mut shown = ancestors.len();
+    let mut breadcrumb = ancestors.join(" › ");
+    while breadcrumb.width() > available && shown > 1 {
+        shown -= 1;
+        breadcrumb = format!("… › {}", ancestors[ancestors.len() - shown..].join(" › "));
+    }
+    format!("{}: {}", label, breadcrumb)
 }
 
 fn render_input_prompt(app: &App, f: &mut Frame, area: Rect) {
     let prompt_text = match &app.input_mode {
         InputMode::Normal => {
-            "Commands: a=add task, s=add subtask, x=toggle, ↑↓/jk=navigate, p=play/pause, r=reset, t=theme, w=save, c=delete, cc=clear all, Esc=menu, q=quit"
+            "Commands: a=add task, s=add subtask, x=toggle, i=edit, f=focus, e=estimate, ↑↓/jk=navigate, PgUp/PgDn/g/G=scroll, JK=move, >/<=indent/outdent, )/(=priority up/down, o=sort by priority, D/S=due/scheduled date, #=tags, T=filter by tag, /=search, n/N=next/previous match, z=fold, M/R=fold/unfold all, p=play/pause, r=reset, d=end phase, ]/[=next/previous phase, +/-=±1 min, v=stats, t=theme, w=save, c=delete, cc=clear all, Esc=menu, q=quit"
         }
-        InputMode::AddingTask => "Enter task name (Enter to confirm, Esc to cancel):",
-        InputMode::AddingSubtask(_) => "Enter subtask name (Enter to confirm, Esc to cancel):",
+        InputMode::AddingTask => "Task (#tag !high due:fri ~3):",
+        InputMode::AddingSubtask(_) => "Subtask (#tag !high due:fri ~3):",
+        InputMode::EditingTask => "Edit task (#tag !high due:fri ~3):",
+        InputMode::EditingDate(DateField::Due) => "Due date (fri, next week, in 3d, 2026-11-02; empty clears):",
+        InputMode::EditingDate(DateField::Scheduled) => "Scheduled date (fri, next week, in 3d, 2026-11-02; empty clears):",
+        InputMode::EditingTags => "Tags separated by spaces, empty to clear (Enter to confirm, Esc to cancel):",
+        InputMode::FilteringTags => "Show tasks with any of these tags, empty to show all (Enter to confirm, Esc to cancel):",
+        InputMode::Searching => "Search titles and tags (Enter to keep the search for n/N, Esc to clear):",
+        InputMode::EditingEstimate => "Estimated pomodoros, empty to clear (Enter to confirm, Esc to cancel):",
         InputMode::Menu => "↑↓/jk=navigate, Enter=select, Esc/q=close",
+        InputMode::Stats => "Esc/q/v=close",
         InputMode::ConfirmingDelete => "Delete selected task/subtask? (y/n):",
         InputMode::ConfirmingClear => "Clear all tasks? (y/n):",
     };
@@ -193,7 +461,7 @@ fn render_input_prompt(app: &App, f: &mut Frame, area: Rect) {
     let input_display = match &app.input_mode {
         InputMode::Normal => String::new(),
         InputMode::ConfirmingDelete | InputMode::ConfirmingClear => String::new(),
-        _ => app.input_buffer.clone(),
+        _ => app.input_buffer.text().to_string(),
     };
 
     let content = match &app.input_mode {
@@ -202,19 +470,61 @@ fn render_input_prompt(app: &App, f: &mut Frame, area: Rect) {
         _ => format!("{} {}", prompt_text, input_display),
     };
 
-    let prompt = Paragraph::new(content)
-        .block(Block::default().borders(Borders::ALL).title("Input"))
-        .style(Style::default().fg(app.theme.get_input_prompt()))
-        .wrap(Wrap { trim: true });
+    let mut title = vec![Span::raw("Input")];
+    if app.is_input_truncated() {
+        title.push(Span::styled(
+            format!(" (too long, will be cut to {} characters)", app.max_title_length),
+            Style::default().fg(app.theme.get_input_warning()).add_modifier(Modifier::BOLD),
+        ));
+    }
 
-    f.render_widget(prompt, area);
+    let mut block = Block::default().borders(Borders::ALL).title(Line::from(title));
+    if let InputMode::EditingDate(_) = app.input_mode {
+        block = block.title_bottom(date_preview(app));
+    }
+
+    let prompt = Paragraph::new(content)
+        .block(block)
+        .style(Style::default().fg(app.theme.get_input_prompt()));
 
     if app.input_mode != InputMode::Normal && app.input_mode != InputMode::ConfirmingDelete && app.input_mode != InputMode::ConfirmingClear {
-        let cursor_pos = prompt_text.len() + 1 + app.input_buffer.len();
-        f.set_cursor(
-            area.x + (cursor_pos as u16 % area.width) + 1,
-            area.y + 1 + (cursor_pos as u16 / area.width),
-        );
+        // Input stays on one line, scrolled sideways to keep the cursor in the box
+        let inner_width = area.width.saturating_sub(2) as usize;
+        let cursor_col = prompt_text.width() + 1 + app.input_buffer.cursor_width();
+        let scroll = (cursor_col + 1).saturating_sub(inner_width);
+        f.render_widget(prompt.scroll((0, scroll as u16)), area);
+        f.set_cursor(area.x + 1 + (cursor_col - scroll) as u16, area.y + 1);
+    } else {
+        f.render_widget(prompt.wrap(Wrap { trim: true }), area);
+    }
+}
+
+/// Shows what the date typed so far resolves to, before it's confirmed.
+fn date_preview(app: &App) -> Line<'static> {
+    let input = app.input_buffer.text();
+    if input.trim().is_empty() {
+        return Line::from(" clears the date ");
+    }
+    let today = Local::now().date_naive();
+    match parse_date(input, today) {
+        Ok(date) => {
+            let days = (date - today).num_days();
+            let relative = match days {
+                0 => "today".to_string(),
+                1 => "tomorrow".to_string(),
+                -1 => "yesterday".to_string(),
+                d if d < 0 => format!("{} days ago", -d),
+                d => format!("in {} days", d),
+            };
+            Line::from(Span::styled(
+                format!(" → {} ({}) ", date.format("%a %b %d %Y"), relative),
+                Style::default().add_modifier(Modifier::BOLD),
+            ))
+        }
+        Err(message) => Line::from(Span::styled(
+            format!(" {} ", message),
+            Style::default().fg(app.theme.get_input_warning()),
+        )),
     }
 }
 
@@ -246,6 +556,78 @@ fn render_menu(app: &App, f: &mut Frame) {
     f.render_widget(menu_list, area);
 }
 
+fn render_stats(app: &App, f: &mut Frame) {
+    let stats = app.get_stats();
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(4),
+            Constraint::Length(3),
+            Constraint::Min(0),
+        ])
+        .split(f.size());
+
+    let today_minutes = stats.daily_minutes.last().map(|(_, m)| *m).unwrap_or(0);
+    let summary = vec![
+        Line::from(format!(
+            "Today: {} focus, {} of {} pomodoros",
+            format_focus_time(today_minutes * 60),
+            stats.today_pomodoros,
+            app.daily_goal
+        )),
+        Line::from(format!(
+            "Current streak: {} day(s)   Longest streak: {} day(s)",
+            stats.current_streak, stats.longest_streak
+        )),
+    ];
+    let summary_widget = Paragraph::new(summary)
+        .block(Block::default().borders(Borders::ALL).title("Statistics"))
+        .style(Style::default().fg(app.theme.get_clock()));
+    f.render_widget(summary_widget, chunks[0]);
+
+    let goal_ratio = if app.daily_goal == 0 {
+        1.0
+    } else {
+        (stats.today_pomodoros as f64 / app.daily_goal as f64).min(1.0)
+    };
+    let goal_gauge = Gauge::default()
+        .block(Block::default().borders(Borders::ALL).title("Daily Goal"))
+        .gauge_style(Style::default().fg(app.theme.get_pomodoro_work()))
+        .ratio(goal_ratio)
+        .label(format!("{}/{}", stats.today_pomodoros, app.daily_goal));
+    f.render_widget(goal_gauge, chunks[1]);
+
+    // Label every fifth day so the labels don't run together
+    let labels: Vec<String> = stats
+        .daily_minutes
+        .iter()
+        .enumerate()
+        .map(|(idx, (date, _))| {
+            if (stats.daily_minutes.len() - 1 - idx).is_multiple_of(5) {
+                date.format("%d").to_string()
+            } else {
+                String::new()
+            }
+        })
+        .collect();
+    let data: Vec<(&str, u64)> = labels
+        .iter()
+        .zip(&stats.daily_minutes)
+        .map(|(label, (_, minutes))| (label.as_str(), *minutes))
+        .collect();
+    let days = data.len().max(1) as u16;
+    let bar_width = (chunks[2].width.saturating_sub(2) / days).saturating_sub(1).max(1);
+    let chart = BarChart::default()
+        .block(Block::default().borders(Borders::ALL).title(format!("Focus minutes, last {} days", days)))
+        .data(&data)
+        .bar_width(bar_width)
+        .bar_gap(1)
+        .bar_style(Style::default().fg(app.theme.get_pomodoro_work()))
+        .value_style(Style::default().fg(app.theme.get_task_normal()).add_modifier(Modifier::REVERSED))
+        .label_style(Style::default().fg(app.theme.get_secondary()));
+    f.render_widget(chart, chunks[2]);
+}
+
 fn centered_rect(percent_x: u16, height: u16, r: Rect) -> Rect {
     let popup_layout = Layout::default()
         .direction(Direction::Vertical)
@@ -266,9 +648,12 @@ fn centered_rect(percent_x: u16, height: u16, r: Rect) -> Rect {
         .split(popup_layout[1])[1]
 }
 
-fn render_save_notification(app: &App, f: &mut Frame) {
-    let area = centered_rect(30, 3, f.size());
-    let notification = Paragraph::new("Saved")
+fn render_notification(app: &App, message: &str, f: &mut Frame) {
+    let lines = message.lines().count().max(1) as u16;
+    let percent_x = if lines > 1 || message.len() > 20 { 60 } else { 30 };
+    let area = centered_rect(percent_x, lines + 2, f.size());
+    f.render_widget(Clear, area);
+    let notification = Paragraph::new(message.to_string())
         .block(Block::default().borders(Borders::ALL))
         .style(Style::default().fg(app.theme.get_secondary()).add_modifier(Modifier::BOLD))
         .alignment(Alignment::Center);